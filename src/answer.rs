@@ -1,4 +1,4 @@
-// Copyright (c) 2024 - 2025 GreenYun Organization
+// Copyright (c) 2024 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::future::Future;
@@ -6,19 +6,40 @@ use std::future::Future;
 use chrono::{DateTime, FixedOffset};
 use tokio::sync::RwLock;
 
-use crate::database::types::lang::Lang;
+use crate::{database::types::lang::Lang, statics::get_bilingual_str};
 
 pub use briefing::Briefing;
 pub use bulletin::Bulletin;
+pub use earthquake::Earthquake;
 pub use forecast::Forecast;
 pub use warning::Warning;
 
+pub use astronomy::to_string as astronomy;
+pub use digest::{skipped_to_bilingual as skipped, to_string as digest};
+pub use district::{rainfall_to_string as rainfall, temperature_to_string as temperatures};
+pub use earthquake::report_to_bilingual as earthquake_report;
+pub use forecast::rain_alert_to_bilingual as rain_alert;
+pub use lunar::{briefing_line as lunar_briefing_line, to_string as lunar};
+pub use notice::to_bilingual as notice;
 pub use setlang::to_string as setlang;
+pub use snooze::to_string as snooze;
+pub use threshold::{alert_to_bilingual as threshold_alert, rule_to_string as threshold_rule};
+pub use tide::{stations_to_string as tide_stations, to_string as tide};
+pub use warning::{
+	cancelled_to_bilingual as warning_cancelled, diff_to_strings as warning_diff, held_to_bilingual as warning_held,
+	piece_to_strings as warning_piece, updated_to_strings as warning_updated,
+};
 
 pub trait Answer {
 	async fn answer(lang: &Lang) -> Vec<String>;
 }
 
+/// Whether the answer is the error of the data being unavailable, rather than
+/// the data itself.
+pub fn is_err(texts: &[String], lang: &Lang) -> bool {
+	matches!(texts, [text] if text == get_bilingual_str!(lang, SERVER_ERROR_TIMEOUT))
+}
+
 #[derive(Clone, Default)]
 struct AnswerEntry {
 	inner: Vec<String>,
@@ -73,9 +94,18 @@ impl AnswerStore {
 	}
 }
 
+mod astronomy;
 mod briefing;
 mod bulletin;
+mod digest;
+mod district;
+mod earthquake;
 mod forecast;
+mod lunar;
 mod macros;
+mod notice;
 mod setlang;
+mod snooze;
+mod threshold;
+mod tide;
 mod warning;
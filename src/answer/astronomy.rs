@@ -0,0 +1,63 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use crate::{
+	database::types::lang::Lang,
+	tool::{mix_strings, types::BilingualString},
+	weather::astronomy::{Body, RiseSet},
+};
+
+use super::macros::zh_num;
+
+const PHASES: [(&str, &str); 8] = [
+	("新月", "New Moon"),
+	("娥眉月", "Waxing Crescent"),
+	("上弦月", "First Quarter"),
+	("盈凸月", "Waxing Gibbous"),
+	("滿月", "Full Moon"),
+	("虧凸月", "Waning Gibbous"),
+	("下弦月", "Last Quarter"),
+	("殘月", "Waning Crescent"),
+];
+
+/// The phase is only given for the moon.
+pub fn to_string(body: Body, date: NaiveDate, times: Option<&RiseSet>, phase: Option<u8>, lang: &Lang) -> String {
+	let mon = zh_num!(date.month());
+	let day = zh_num!(date.day());
+	let date = date.format("%d %B");
+
+	let (mut zh, mut en) = match body {
+		Body::Sun => (format!("<b>{mon}月{day}日日出日落</b>"), format!("<b>Sunrise and Sunset on {date}</b>")),
+		Body::Moon => (format!("<b>{mon}月{day}日月出月落</b>"), format!("<b>Moonrise and Moonset on {date}</b>")),
+	};
+
+	if let Some(t) = times {
+		// The moon does not rise or set on some days.
+		let format = |time: Option<NaiveTime>| time.map_or_else(|| "—".to_string(), |t| t.format("%H:%M").to_string());
+		let (rise, transit, set) = (format(t.rise), format(t.transit), format(t.set));
+		let (rise_zh, set_zh) = match body {
+			Body::Sun => ("日出", "日落"),
+			Body::Moon => ("月出", "月落"),
+		};
+
+		write!(zh, "\n{rise_zh}：{rise}\n中天：{transit}\n{set_zh}：{set}").ok();
+		write!(en, "\nRise: {rise}\nTransit: {transit}\nSet: {set}").ok();
+	} else {
+		zh.push_str("\n當日未有完整嘅時間資料。");
+		en.push_str("\nThe times are not available on the day.");
+	}
+
+	let mut list = vec![BilingualString::new(zh, en)];
+
+	if let Some(&(zh, en)) = phase.and_then(|phase| PHASES.get(usize::from(phase))) {
+		list.push(BilingualString::new(format!("月相：{zh}"), format!("Moon phase: {en}")));
+	}
+
+	list.push(BilingualString::new("<i>時間為香港時間。</i>", "<i>Times are in Hong Kong Time.</i>"));
+
+	mix_strings(lang, &list)
+}
@@ -1,4 +1,4 @@
-// Copyright (c) 2024 - 2025 GreenYun Organization
+// Copyright (c) 2024 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::{fmt::Write, sync::LazyLock};
@@ -57,6 +57,9 @@ fn to_string(data: &Data, lang: &Lang) -> Vec<String> {
 	let chi_hour = chinese_hour(pm, hour12);
 	let eng_hour = english_hour(pm, hour12);
 
+	// Readings missing from the Observatory station
+	let reading = |value: Option<f32>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+
 	let (chi_temp, chi_uv) = if matches!(lang, Lang::English) {
 		(String::new(), String::new())
 	} else {
@@ -67,7 +70,8 @@ fn to_string(data: &Data, lang: &Lang) -> Vec<String> {
         	氣溫：<b>{}</b> 度\n\
         	相對濕度：百分之 <b>{}</b>\n\
         	<b>{chi_weather_desc}</b>",
-			data.temperature, data.humidity,
+			reading(data.temperature),
+			reading(data.humidity),
 		);
 		let chi_uv = data.uv_index.clone().map_or_else(String::new, |uv_index| {
 			format!(
@@ -91,7 +95,8 @@ fn to_string(data: &Data, lang: &Lang) -> Vec<String> {
     		Air temperature: <b>{}</b> degrees Celsius\n\
         	Relative humidity: <b>{}</b> per cent\n\
         	<b>{eng_weather_desc}</b>",
-			data.temperature, data.humidity,
+			reading(data.temperature),
+			reading(data.humidity),
 		);
 		let eng_uv = data.uv_index.clone().map_or_else(String::new, |uv_index| {
 			format!(
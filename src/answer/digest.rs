@@ -0,0 +1,27 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use crate::{
+	database::types::{lang::Lang, product::Product},
+	tool::{mix_strings, types::BilingualString},
+};
+
+pub fn to_string(entries: &[BilingualString], lang: &Lang) -> String {
+	let mut list = Vec::with_capacity(entries.len() + 2);
+
+	list.push(BilingualString::new(
+		"<b>靜音或暫停推送期間嘅通知：</b>",
+		"<b>Notifications held back during quiet hours or snooze:</b>",
+	));
+	list.extend(entries.iter().cloned());
+	list.push(BilingualString::new("請用 /warning 查閱現行警告詳情。", "Use /warning for the warnings in force."));
+
+	mix_strings(lang, &list)
+}
+
+pub fn skipped_to_bilingual(product: Product) -> BilingualString {
+	BilingualString::new(
+		format!("已略過定時發送：{}", product.title(&Lang::Chinese)),
+		format!("Skipped scheduled delivery: {}", product.title(&Lang::English)),
+	)
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use crate::{
+	database::types::lang::Lang,
+	tool::{mix_strings, types::BilingualString},
+	weather::{
+		Bulletin,
+		bulletin::{BulletinRainfall, BulletinTemperature},
+	},
+};
+
+pub fn temperature_to_string(data: &Bulletin, readings: &[BulletinTemperature], lang: &Lang) -> String {
+	let (zh, en): (Vec<_>, Vec<_>) = readings
+		.iter()
+		.map(|t| (format!("{:x}：<b>{}</b> 度", t.place, t.value), format!("{:e}: <b>{}</b> °C", t.place, t.value)))
+		.unzip();
+
+	mix_strings(lang, &[
+		BilingualString::new("<b>各區氣溫</b>", "<b>Temperatures</b>"),
+		BilingualString::new(zh.join("\n"), en.join("\n")),
+		BilingualString::new(
+			format!("<i>@ {}</i>", data.temperature_time),
+			format!("<i>@ {}</i>", data.temperature_time),
+		),
+	])
+}
+
+pub fn rainfall_to_string(data: &Bulletin, readings: &[BulletinRainfall], lang: &Lang) -> String {
+	let (zh, en): (Vec<_>, Vec<_>) = readings
+		.iter()
+		.map(|r| {
+			let (value_zh, value_en) = match (r.maintenance, r.min, r.max) {
+				(true, ..) => ("維修中".to_string(), "under maintenance".to_string()),
+				(false, Some(min), Some(max)) => {
+					(format!("<b>{min}</b> 至 <b>{max}</b> 毫米"), format!("<b>{min}</b> to <b>{max}</b> mm"))
+				}
+				(false, None, Some(max)) => (format!("<b>{max}</b> 毫米"), format!("<b>{max}</b> mm")),
+				(false, _, None) => ("沒有資料".to_string(), "not available".to_string()),
+			};
+			(format!("{:x}：{value_zh}", r.place), format!("{:e}: {value_en}", r.place))
+		})
+		.unzip();
+
+	let (start, end) = (data.rainfall_start_time.format("%H:%M"), data.rainfall_end_time.format("%H:%M"));
+
+	mix_strings(lang, &[
+		BilingualString::new(
+			format!("<b>各區雨量</b>\n{start} 至 {end}"),
+			format!("<b>Rainfall</b>\nFrom {start} to {end}"),
+		),
+		BilingualString::new(zh.join("\n"), en.join("\n")),
+	])
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{fmt::Write, sync::LazyLock};
+
+use chrono::DateTime;
+
+use crate::{
+	database::types::lang::Lang,
+	statics::get_bilingual_str,
+	tool::{mix_strings, types::BilingualString},
+	weather::{Earthquake as Data, WeatherData as _, earthquake::EarthquakeReport},
+};
+
+use super::{Answer, AnswerEntry, AnswerStore};
+
+static ANSWER: LazyLock<AnswerStore> = LazyLock::new(AnswerStore::default);
+
+pub struct Earthquake;
+
+impl Answer for Earthquake {
+	async fn answer(lang: &Lang) -> Vec<String> {
+		let entry = ANSWER.update_and_get(lang, update).await;
+		entry.inner
+	}
+}
+
+async fn update(lang: &Lang, entry: AnswerEntry) -> AnswerEntry {
+	let timeout_err = get_bilingual_str!(lang, SERVER_ERROR_TIMEOUT);
+
+	let Some(data) = Data::get().await else {
+		return AnswerEntry::new_err(timeout_err);
+	};
+
+	let message_title = BilingualString::new("<b>地震速報</b>", "<b>Quick Earthquake Message</b>");
+	let felt_title = BilingualString::new("<b>本地有感地震報告</b>", "<b>Locally Felt Earth Tremor Report</b>");
+	let reports = [data.message.map(|r| (message_title, r)), data.felt.map(|r| (felt_title, r))];
+
+	if reports.iter().all(Option::is_none) {
+		return AnswerEntry::new(vec![], DateTime::default());
+	}
+
+	let update_time = reports.iter().flatten().map(|(_, r)| r.update_time).max().unwrap_or_default();
+
+	if entry.update_time >= update_time {
+		return entry;
+	}
+
+	let inner = reports
+		.into_iter()
+		.flatten()
+		.map(|(title, r)| {
+			let mut text = mix_strings(lang, &[title, report_to_bilingual(&r)]);
+			write!(text, "\n\n<i>@ {}</i>", r.update_time).ok();
+			text
+		})
+		.collect();
+
+	AnswerEntry::new(inner, update_time)
+}
+
+pub fn report_to_bilingual(r: &EarthquakeReport) -> BilingualString {
+	let time = r.occur_time.format("%Y-%m-%d %H:%M");
+	let (lat_zh, lat_en) = if r.latitude < 0.0 { ("南緯", "S") } else { ("北緯", "N") };
+	let (lon_zh, lon_en) = if r.longitude < 0.0 { ("西經", "W") } else { ("東經", "E") };
+	let (lat, lon) = (r.latitude.abs(), r.longitude.abs());
+
+	let mut text = BilingualString::new(
+		format!("{time}，{:x}（{lat_zh}{lat}度，{lon_zh}{lon}度）發生<b>{:.1}</b>級地震。", r.region, r.magnitude),
+		format!(
+			"An earthquake of magnitude <b>{:.1}</b> occurred at {:e} ({lat}°{lat_en}, {lon}°{lon_en}) at {time}.",
+			r.magnitude, r.region
+		),
+	);
+
+	if let Some(intensity) = r.intensity {
+		text = text
+			+ BilingualString::new(
+				format!("\n香港錄得嘅最高烈度為修訂麥加利地震烈度{intensity}度。"),
+				format!("\nThe maximum intensity in Hong Kong was {intensity} on the Modified Mercalli Scale."),
+			);
+	}
+
+	for detail in &r.details {
+		text = text + BilingualString::new("\n", "\n") + detail.clone();
+	}
+
+	text
+}
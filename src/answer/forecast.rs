@@ -1,4 +1,4 @@
-// Copyright (c) 2024 - 2025 GreenYun Organization
+// Copyright (c) 2024 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::{fmt::Write, sync::LazyLock};
@@ -89,3 +89,21 @@ fn daily_string(lang: &Lang, data: &DailyForecast) -> String {
 
 	lang.map(format!("{zh}\n\n{en}"), zh, en)
 }
+
+pub fn rain_alert_to_bilingual(data: &DailyForecast, raised: bool) -> BilingualString {
+	let mon = zh_num!(data.date.month());
+	let day = zh_num!(data.date.day());
+	let date = data.date.format("%d %B");
+
+	if raised {
+		BilingualString::new(
+			format!("天文台將明日（{mon}月{day}日）嘅顯著降雨概率調高至<b>{:x}</b>。", data.psr),
+			format!("The Observatory has raised the PSR for tomorrow ({date}) to <b>{:e}</b>.", data.psr),
+		)
+	} else {
+		BilingualString::new(
+			format!("明日（{mon}月{day}日）嘅顯著降雨概率為<b>{:x}</b>。", data.psr),
+			format!("The PSR for tomorrow ({date}) is <b>{:e}</b>.", data.psr),
+		)
+	}
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+	database::types::lang::Lang,
+	tool::{mix_strings, time::hong_kong_now, types::BilingualString},
+	weather::lunar::{self, LunarDate, next_solar_term, solar_term},
+};
+
+use super::macros::zh_num;
+
+/// Solar terms by the multiple of 15 degrees of the longitude of the sun, from
+/// the Vernal Equinox, with the English names used by the Observatory.
+const SOLAR_TERMS: [(&str, &str); 24] = [
+	("春分", "Vernal Equinox"),
+	("清明", "Bright and Clear"),
+	("穀雨", "Corn Rain"),
+	("立夏", "Summer Commences"),
+	("小滿", "Corn Forms"),
+	("芒種", "Corn on Ear"),
+	("夏至", "Summer Solstice"),
+	("小暑", "Moderate Heat"),
+	("大暑", "Great Heat"),
+	("立秋", "Autumn Commences"),
+	("處暑", "End of Heat"),
+	("白露", "White Dew"),
+	("秋分", "Autumnal Equinox"),
+	("寒露", "Cold Dew"),
+	("霜降", "Frost"),
+	("立冬", "Winter Commences"),
+	("小雪", "Light Snow"),
+	("大雪", "Heavy Snow"),
+	("冬至", "Winter Solstice"),
+	("小寒", "Moderate Cold"),
+	("大寒", "Severe Cold"),
+	("立春", "Spring Commences"),
+	("雨水", "Spring Showers"),
+	("驚蟄", "Insects Waken"),
+];
+
+pub fn to_string(date: NaiveDate, lunar: &LunarDate, lang: &Lang) -> String {
+	let mon = zh_num!(date.month());
+	let day = zh_num!(date.day());
+
+	let mut list = vec![
+		BilingualString::new(
+			format!("<b>{}年{mon}月{day}日</b>", date.year()),
+			format!("<b>{}</b>", date.format("%d %B %Y")),
+		),
+		BilingualString::new(
+			format!("農曆{}\n{}", lunar.date, lunar.year),
+			format!("Lunar date: {}\nLunar year: {}", lunar.date, lunar.year),
+		),
+	];
+
+	if let Some(&(zh, en)) = solar_term(date).and_then(|term| SOLAR_TERMS.get(term)) {
+		list.push(BilingualString::new(format!("今日係{zh}。"), format!("It is {en} ({zh}) today.")));
+	} else if let Some((next, term)) = next_solar_term(date) {
+		let (zh, en) = SOLAR_TERMS[term];
+		list.push(BilingualString::new(
+			format!("下一個節氣：{}月{}日{zh}", zh_num!(next.month()), zh_num!(next.day())),
+			format!("Next solar term: {en} ({zh}) on {}", next.format("%d %B")),
+		));
+	}
+
+	mix_strings(lang, &list)
+}
+
+/// The lunar date of today appended to the local weather forecast, which is
+/// only given in Chinese.
+pub async fn briefing_line(lang: &Lang) -> Option<String> {
+	if matches!(lang, Lang::English) {
+		return None;
+	}
+
+	let today = hong_kong_now().date_naive();
+	let lunar = lunar::get(today).await?;
+
+	Some(line_to_string(today, &lunar))
+}
+
+fn line_to_string(date: NaiveDate, lunar: &LunarDate) -> String {
+	let term = solar_term(date).and_then(|term| SOLAR_TERMS.get(term)).map(|(zh, _)| format!("　{zh}"));
+
+	format!("農曆{}{}{}", lunar.year.split('，').next().unwrap_or_default(), lunar.date, term.unwrap_or_default())
+}
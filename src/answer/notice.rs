@@ -0,0 +1,20 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use crate::{database::types::notice_kind::NoticeKind, tool::types::BilingualString};
+
+pub fn to_bilingual(kind: NoticeKind, text: &BilingualString) -> BilingualString {
+	let title = title(kind);
+	BilingualString::new(format!("{}\n{}", title.zh, text.zh), format!("{}\n{}", title.en, text.en))
+}
+
+fn title(kind: NoticeKind) -> BilingualString {
+	match kind {
+		NoticeKind::SpecialTip => BilingualString::new("<b>特別天氣提示：</b>", "<b>Special Weather Tips:</b>"),
+		NoticeKind::RainstormReminder => BilingualString::new("<b>暴雨提示：</b>", "<b>Rainstorm Reminder:</b>"),
+		NoticeKind::FireDanger => BilingualString::new("<b>火災危險警告：</b>", "<b>Fire Danger Warning:</b>"),
+		NoticeKind::FeltEarthquake => {
+			BilingualString::new("<b>本地有感地震：</b>", "<b>Locally Felt Earth Tremor:</b>")
+		}
+	}
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+	database::types::lang::Lang,
+	tool::{mix_string, time::HONG_KONG, types::BilingualString},
+};
+
+pub fn to_string(until: Option<DateTime<Utc>>, lang: &Lang) -> String {
+	let text = match until {
+		Some(until) => {
+			let until = until.with_timezone(&HONG_KONG).format("%Y-%m-%d %H:%M");
+			BilingualString::new(
+				format!("已暫停自動推送，直至 {until}（香港時間）。期間略過嘅通知會喺之後一併報告。"),
+				format!(
+					"Automatic messages are snoozed until {until} (Hong Kong Time). What is skipped meanwhile will be \
+					 reported afterwards."
+				),
+			)
+		}
+		None => BilingualString::new("已恢復自動推送。", "Automatic messages have been resumed."),
+	};
+
+	mix_string(lang, &text.as_str())
+}
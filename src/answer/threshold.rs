@@ -0,0 +1,28 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use crate::{
+	database::{entities::threshold::Threshold, types::lang::Lang},
+	tool::types::BilingualString,
+};
+
+pub fn rule_to_string(t: &Threshold, lang: &Lang) -> String {
+	format!("{} {} {}{}", t.metric.name(lang), if t.above { "≥" } else { "≤" }, t.value, t.metric.unit())
+}
+
+pub fn alert_to_bilingual(t: &Threshold, reading: f32) -> BilingualString {
+	let unit = t.metric.unit();
+	let op = if t.above { "≥" } else { "≤" };
+	BilingualString::new(
+		format!(
+			"現時{}為 <b>{reading}{unit}</b>，已達到你設定嘅 {op} {}{unit}。",
+			t.metric.name(&Lang::Chinese),
+			t.value
+		),
+		format!(
+			"Current {}: <b>{reading}{unit}</b>, reaching your threshold of {op} {}{unit}.",
+			t.metric.name(&Lang::English),
+			t.value
+		),
+	)
+}
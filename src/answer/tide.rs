@@ -0,0 +1,57 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use chrono::Datelike;
+use hko::opendata::SeaStation;
+
+use crate::{
+	database::types::lang::Lang,
+	tool::{mix_strings, types::BilingualString},
+	weather::tide::{STATIONS, Tide, station_name},
+};
+
+use super::macros::zh_num;
+
+pub fn to_string(station: SeaStation, tides: &[Tide], lang: &Lang) -> String {
+	let (name_zh, name_en) = station_name(station);
+
+	let mut list = vec![BilingualString::new(
+		format!("<b>{name_zh}潮汐預測</b>"),
+		format!("<b>Tide Predictions at {name_en}</b>"),
+	)];
+
+	for day in tides.chunk_by(|a, b| a.time.date() == b.time.date()) {
+		let date = day[0].time.date();
+
+		let mut zh = format!("<b>{}月{}日</b>", zh_num!(date.month()), zh_num!(date.day()));
+		let mut en = format!("<b>{}</b>", date.format("%d %B"));
+
+		for t in day {
+			let time = t.time.format("%H:%M");
+			let (kind_zh, kind_en) = if t.high { ("高潮", "High") } else { ("低潮", "Low") };
+			write!(zh, "\n{kind_zh} {time}　{:.1} 米", t.height).ok();
+			write!(en, "\n{kind_en} {time}  {:.1} m", t.height).ok();
+		}
+
+		list.push(BilingualString::new(zh, en));
+	}
+
+	list.push(BilingualString::new(
+		"<i>時間為香港時間，潮高由海圖基準面起計。</i>",
+		"<i>Times are in Hong Kong Time, heights are above Chart Datum.</i>",
+	));
+
+	mix_strings(lang, &list)
+}
+
+pub fn stations_to_string(lang: &Lang) -> String {
+	STATIONS
+		.iter()
+		.map(|(station, zh, en)| {
+			lang.map(format!("{station} {zh} {en}"), format!("{station} {zh}"), format!("{station} {en}"))
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
@@ -1,15 +1,19 @@
-// Copyright (c) 2024 - 2025 GreenYun Organization
+// Copyright (c) 2024 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
-use std::{fmt::Write, sync::LazyLock};
+use std::sync::LazyLock;
 
 use chrono::DateTime;
 
 use crate::{
 	database::types::lang::Lang,
 	statics::get_bilingual_str,
-	tool::mix_strings,
-	weather::{Warning as Data, WeatherData as _},
+	tool::{
+		diff::{Change, diff, sentences},
+		mix_string, mix_strings,
+		types::BilingualString,
+	},
+	weather::{Warning as Data, WeatherData as _, warning::Piece},
 };
 
 use super::{Answer, AnswerEntry, AnswerStore};
@@ -49,29 +53,127 @@ async fn update(lang: &Lang, entry: AnswerEntry) -> AnswerEntry {
 }
 
 fn to_strings(data: Data, lang: &Lang) -> Vec<String> {
-	if data.pieces.is_empty() {
-		return Vec::new();
+	data.pieces.iter().flat_map(|p| piece_to_strings(p, lang)).collect()
+}
+
+pub fn piece_to_strings(p: &Piece, lang: &Lang) -> Vec<String> {
+	with_footer(p, lang, |_| format!("<i>@ {}</i>", p.update_time))
+}
+
+/// The warning to edit its existing messages with, which ends with the time it
+/// is updated instead of the time it is issued.
+pub fn updated_to_strings(p: &Piece, lang: &Lang) -> Vec<String> {
+	with_footer(p, lang, |lang| updated_to_string(p, lang))
+}
+
+fn with_footer(p: &Piece, lang: &Lang, footer: impl Fn(&Lang) -> String) -> Vec<String> {
+	let mut list = vec!["<b>".to_string() + p.name.clone() + "</b>"];
+	list.extend_from_slice(&p.contents);
+
+	split_if_long(lang, |lang| format!("{}\n\n{}", mix_strings(lang, &list), footer(lang)))
+}
+
+/// Splits the text into one message per language if too long for a single one.
+fn split_if_long(lang: &Lang, text: impl Fn(&Lang) -> String) -> Vec<String> {
+	let mixed = text(lang);
+
+	if matches!(lang, Lang::Bilingual) && mixed.len() > 4000 {
+		[Lang::Chinese, Lang::English].iter().map(text).collect()
+	} else {
+		vec![mixed]
 	}
+}
 
-	let mut pieces = Vec::new();
+/// Stands for the paragraphs unchanged between issuances.
+const ELLIPSIS: &str = "…";
 
-	for p in data.pieces {
-		let mut list = vec!["<b>".to_string() + p.name + "</b>"];
-		list.extend_from_slice(&p.contents);
+/// Compares the warning with the contents of its previous issuance, returns
+/// `None` if nothing has changed in the language of the chat.
+pub fn diff_to_strings(previous: &[BilingualString], p: &Piece, lang: &Lang) -> Option<Vec<String>> {
+	let (old_zh, old_en): (Vec<_>, Vec<_>) = previous.iter().map(|s| (s.zh.as_str(), s.en.as_str())).unzip();
+	let (new_zh, new_en): (Vec<_>, Vec<_>) = p.contents.iter().map(|s| (s.zh.as_str(), s.en.as_str())).unzip();
 
-		let mut text = mix_strings(lang, &list);
+	let changes = BilingualString::new(render_diff(&old_zh, &new_zh), render_diff(&old_en, &new_en));
+	if mix_string(lang, &changes.as_str()).is_empty() {
+		return None;
+	}
 
-		if matches!(lang, Lang::Bilingual) && text.len() > 4000 {
-			text = mix_strings(&Lang::Chinese, &list);
-			write!(text, "\n\n<i>@ {}</i>", p.update_time).ok();
-			pieces.push(text);
+	let title = BilingualString::new(
+		format!("<b>{:x}</b>與上次發出時比較：", p.name),
+		format!("Changes to the <b>{:e}</b> since the previous issuance:", p.name),
+	);
 
-			text = mix_strings(&Lang::English, &list);
+	Some(split_if_long(lang, |lang| mix_strings(lang, &[title.clone(), changes.clone()])))
+}
+
+/// Renders the paragraph-level changes, with removed sentences struck through
+/// and added ones underlined. Runs of unchanged paragraphs are collapsed into
+/// an ellipsis.
+fn render_diff(old: &[&str], new: &[&str]) -> String {
+	let mut paragraphs = Vec::new();
+	let (mut removed, mut added) = (Vec::new(), Vec::new());
+
+	for change in diff(old, new) {
+		match change {
+			Change::Removed(&p) => removed.push(p),
+			Change::Added(&p) => added.push(p),
+			Change::Same(_) => {
+				if !removed.is_empty() || !added.is_empty() {
+					paragraphs.push(render_hunk(&removed, &added));
+					removed.clear();
+					added.clear();
+				}
+
+				if paragraphs.last().is_none_or(|p| p != ELLIPSIS) {
+					paragraphs.push(ELLIPSIS.to_string());
+				}
+			}
 		}
+	}
+
+	if !removed.is_empty() || !added.is_empty() {
+		paragraphs.push(render_hunk(&removed, &added));
+	}
 
-		write!(text, "\n\n<i>@ {}</i>", p.update_time).ok();
-		pieces.push(text);
+	if paragraphs.iter().all(|p| p == ELLIPSIS) {
+		return String::new();
 	}
 
-	pieces
+	paragraphs.join("\n\n")
+}
+
+/// Renders the changed paragraphs as one, sentence by sentence.
+fn render_hunk(removed: &[&str], added: &[&str]) -> String {
+	let old = removed.iter().flat_map(|p| sentences(p)).collect::<Vec<_>>();
+	let new = added.iter().flat_map(|p| sentences(p)).collect::<Vec<_>>();
+
+	diff(&old, &new)
+		.into_iter()
+		.map(|change| match change {
+			Change::Same(s) => (*s).to_string(),
+			Change::Removed(s) => format!("<s>{s}</s>"),
+			Change::Added(s) => format!("<u>{s}</u>"),
+		})
+		.collect()
+}
+
+pub fn cancelled_to_bilingual(name: &BilingualString) -> BilingualString {
+	BilingualString::new(format!("<b>{name:x}</b>已經取消。"), format!("The <b>{name:e}</b> has been cancelled."))
+}
+
+/// A line in the digest of warnings held back during quiet hours.
+pub fn held_to_bilingual(p: &Piece) -> BilingualString {
+	BilingualString::new(
+		format!("<b>{:x}</b>（{}）", p.name, p.update_time),
+		format!("<b>{:e}</b> ({})", p.name, p.update_time),
+	)
+}
+
+fn updated_to_string(p: &Piece, lang: &Lang) -> String {
+	let text = BilingualString::new(
+		format!("<i>更新於 {}</i>", p.update_time),
+		format!("<i>Updated at {}</i>", p.update_time),
+	);
+
+	mix_string(lang, &text.as_str())
 }
@@ -1,26 +1,51 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
-use std::{env, io, path::Path, process};
+use std::{env, io, path::Path, process, str::FromStr};
 
-use crate::NAME_VERSION_STRING;
+use crate::{
+	NAME_VERSION_STRING,
+	database::{entities::channel::Channel, types::lang::Lang},
+};
 
 #[derive(Clone)]
 pub struct Args {
 	pub bot: String,
 	pub db_uri: String,
+	pub channels: Vec<Channel>,
+	pub admins: Vec<u64>,
+	pub edit_warnings: bool,
+	pub require_start: bool,
 }
 
 impl Args {
 	pub fn new() -> Self {
 		let mut bot = String::new();
 		let mut db_uri = String::new();
+		let mut channels = Vec::new();
+		let mut admins = Vec::new();
+		let mut edit_warnings = false;
+		let mut require_start = false;
 
 		env::vars_os()
 			.map(|(k, v)| (k.to_string_lossy().into_owned().to_uppercase(), v.to_string_lossy().into_owned()))
 			.for_each(|(key, val)| match key.as_str() {
 				"HKO_BOT_TOKEN" => bot = val,
 				"HKO_BOT_DATABASE_URI" => db_uri = val,
+				"HKO_BOT_CHANNELS" => {
+					channels = val.split(',').filter(|s| !s.trim().is_empty()).map(parse_channel).collect();
+				}
+				"HKO_BOT_ADMINS" => {
+					admins = val.split(',').filter(|s| !s.trim().is_empty()).map(parse_admin).collect();
+				}
+				"HKO_BOT_EDIT_WARNINGS" => {
+					edit_warnings =
+						!val.is_empty() && !matches!(val.to_ascii_lowercase().as_str(), "0" | "false" | "no");
+				}
+				"HKO_BOT_REQUIRE_START" => {
+					require_start =
+						!val.is_empty() && !matches!(val.to_ascii_lowercase().as_str(), "0" | "false" | "no");
+				}
 				_ => (),
 			});
 
@@ -44,6 +69,24 @@ impl Args {
 					db_uri = args_os.next().unwrap_or_else(|| usage_then_exit(1)).to_string_lossy().into_owned();
 				}
 
+				// Broadcast channel, with optional language
+				"-c" => {
+					let arg = args_os.next().unwrap_or_else(|| usage_then_exit(1));
+					channels.push(parse_channel(&arg.to_string_lossy()));
+				}
+
+				// Administrator user ID
+				"-a" => {
+					let arg = args_os.next().unwrap_or_else(|| usage_then_exit(1));
+					admins.push(parse_admin(&arg.to_string_lossy()));
+				}
+
+				// Edit warning messages in place
+				"-e" => edit_warnings = true,
+
+				// Require /start before any other command
+				"-r" => require_start = true,
+
 				_ => usage_then_exit(1),
 			}
 		}
@@ -58,10 +101,33 @@ impl Args {
 			usage_then_exit(1);
 		}
 
-		Self { bot, db_uri }
+		Self { bot, db_uri, channels, admins, edit_warnings, require_start }
 	}
 }
 
+fn parse_channel(s: &str) -> Channel {
+	let (id, lang) = s.trim().split_once(':').unwrap_or((s.trim(), "bilingual"));
+
+	let Ok(id) = id.parse() else {
+		eprintln!("error: invalid channel ID: {id}");
+		usage_then_exit(1);
+	};
+
+	let Ok(lang) = Lang::from_str(lang) else {
+		eprintln!("error: invalid channel language: {lang}");
+		usage_then_exit(1);
+	};
+
+	Channel { id, lang }
+}
+
+fn parse_admin(s: &str) -> u64 {
+	s.trim().parse().unwrap_or_else(|_| {
+		eprintln!("error: invalid administrator user ID: {s}");
+		usage_then_exit(1);
+	})
+}
+
 fn program_call_name() -> String {
 	let exe_path = env::args_os().next().unwrap_or_default();
 	let exe_path = Path::new(&exe_path);
@@ -70,7 +136,12 @@ fn program_call_name() -> String {
 }
 
 fn usage(mut w: impl io::Write) {
-	writeln!(w, "usage: {} [-k bot_token] [-s database_uri]", program_call_name()).ok();
+	writeln!(
+		w,
+		"usage: {} [-e] [-r] [-k bot_token] [-s database_uri] [-c channel_id[:lang]]... [-a admin_user_id]...",
+		program_call_name()
+	)
+	.ok();
 }
 
 fn usage_then_exit(exit_val: i32) -> ! {
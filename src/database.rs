@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 use log::LevelFilter;
@@ -29,6 +29,49 @@ impl Connection {
 
 		Ok(Self { pool: PgPool::connect_with(conn_opt).await? })
 	}
+
+	/// Removes everything about a chat that the bot can no longer reach.
+	pub async fn forget_chat(&self, chat_id: i64) -> Result<(), Error> {
+		let mut tx = self.pool.begin().await?;
+
+		sqlx::query("DELETE FROM chat WHERE id = $1").bind(chat_id).execute(&mut *tx).await?;
+		sqlx::query("DELETE FROM channel WHERE id = $1").bind(chat_id).execute(&mut *tx).await?;
+		sqlx::query("DELETE FROM warning_delivery WHERE chat_id = $1").bind(chat_id).execute(&mut *tx).await?;
+
+		tx.commit().await
+	}
+
+	/// Moves everything about a group to the supergroup it was upgraded to.
+	///
+	/// Tables referencing `chat (id)` follow the change with `ON UPDATE
+	/// CASCADE`. If the supergroup has been registered already, the group is
+	/// simply removed.
+	pub async fn migrate_chat(&self, from: i64, to: i64) -> Result<(), Error> {
+		let mut tx = self.pool.begin().await?;
+
+		sqlx::query(
+			"UPDATE chat SET id = $2, pinned_message_id = NULL WHERE id = $1 AND NOT EXISTS (SELECT 1 FROM chat \
+			 WHERE id = $2)",
+		)
+		.bind(from)
+		.bind(to)
+		.execute(&mut *tx)
+		.await?;
+		sqlx::query("DELETE FROM chat WHERE id = $1").bind(from).execute(&mut *tx).await?;
+
+		// Message IDs are not carried over to the supergroup.
+		sqlx::query(
+			"UPDATE warning_delivery AS d SET chat_id = $2, message_ids = '{}' WHERE chat_id = $1 AND NOT EXISTS \
+			 (SELECT 1 FROM warning_delivery WHERE chat_id = $2 AND code = d.code AND subtype = d.subtype)",
+		)
+		.bind(from)
+		.bind(to)
+		.execute(&mut *tx)
+		.await?;
+		sqlx::query("DELETE FROM warning_delivery WHERE chat_id = $1").bind(from).execute(&mut *tx).await?;
+
+		tx.commit().await
+	}
 }
 
 pub async fn connect<S>(uri: S) -> Connection
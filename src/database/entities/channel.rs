@@ -0,0 +1,49 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, types::lang::Lang};
+
+// CREATE TABLE channel (id bigint PRIMARY KEY, lang lang NOT NULL);
+#[derive(Clone, FromRow)]
+pub struct Channel {
+	pub id: i64,
+	pub lang: Lang,
+}
+
+impl Connection {
+	pub async fn insert_channel(&self, channel: &Channel) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"INSERT INTO channel (id, lang) VALUES ($1, $2) ON CONFLICT (id) DO UPDATE SET lang = EXCLUDED.lang",
+		)
+		.bind(channel.id)
+		.bind(&channel.lang)
+		.execute(&self.pool)
+		.await
+	}
+
+	pub async fn delete_channel(&self, channel_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM channel WHERE id = $1").bind(channel_id).execute(&self.pool).await
+	}
+
+	pub async fn select_channels(&self) -> Result<Vec<Channel>, Error> {
+		sqlx::query_as("SELECT id, lang FROM channel ORDER BY id").fetch_all(&self.pool).await
+	}
+
+	/// Registers the channels given at startup, leaving those already
+	/// registered as they are, so the languages changed at runtime are kept.
+	pub async fn register_channels(&self, channels: &[Channel]) {
+		for channel in channels {
+			let res = sqlx::query("INSERT INTO channel (id, lang) VALUES ($1, $2) ON CONFLICT (id) DO NOTHING")
+				.bind(channel.id)
+				.bind(&channel.lang)
+				.execute(&self.pool)
+				.await;
+
+			if let Err(e) = res {
+				log::error!("failed to register channel {}: {e}", channel.id);
+			}
+		}
+	}
+}
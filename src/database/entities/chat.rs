@@ -1,24 +1,72 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
+use chrono::{DateTime, NaiveTime, Utc};
 use sqlx::{Error, FromRow, postgres::PgQueryResult};
 
 use crate::database::{Connection, types::lang::Lang};
 
-// CREATE TABLE chat (id bigint PRIMARY KEY, lang lang NOT NULL);
+// CREATE TABLE chat (
+//     id bigint PRIMARY KEY,
+//     lang lang NOT NULL,
+//     pin_warning boolean NOT NULL DEFAULT false,
+//     pinned_message_id integer,
+//     quiet_start time,
+//     quiet_end time,
+//     silent_minor boolean NOT NULL DEFAULT false,
+//     snooze_until timestamptz
+// );
 #[derive(Clone, FromRow)]
 pub struct Chat {
 	pub id: i64,
 	pub lang: Lang,
+	pub pin_warning: bool,
+	pub pinned_message_id: Option<i32>,
+	pub quiet_start: Option<NaiveTime>,
+	pub quiet_end: Option<NaiveTime>,
+	pub silent_minor: bool,
+	pub snooze_until: Option<DateTime<Utc>>,
+}
+
+impl Chat {
+	pub const fn new(id: i64, lang: Lang) -> Self {
+		Self {
+			id,
+			lang,
+			pin_warning: false,
+			pinned_message_id: None,
+			quiet_start: None,
+			quiet_end: None,
+			silent_minor: false,
+			snooze_until: None,
+		}
+	}
+
+	/// Whether the time, in Hong Kong local time, falls within the quiet hours.
+	pub fn is_quiet(&self, time: NaiveTime) -> bool {
+		match (self.quiet_start, self.quiet_end) {
+			(Some(start), Some(end)) if start <= end => start <= time && time < end,
+			(Some(start), Some(end)) => start <= time || time < end,
+			_ => false,
+		}
+	}
+
+	pub fn is_snoozed(&self, now: DateTime<Utc>) -> bool {
+		self.snooze_until.is_some_and(|until| now < until)
+	}
 }
 
 impl Connection {
 	pub async fn insert_chat(&self, chat: &Chat) -> Result<PgQueryResult, Error> {
-		sqlx::query("INSERT INTO chat (id, lang) VALUES ($1, $2)")
-			.bind(chat.id)
-			.bind(&chat.lang)
-			.execute(&self.pool)
-			.await
+		sqlx::query(
+			"INSERT INTO chat (id, lang, pin_warning, silent_minor) VALUES ($1, $2, $3, $4) ON CONFLICT (id) DO NOTHING",
+		)
+		.bind(chat.id)
+		.bind(&chat.lang)
+		.bind(chat.pin_warning)
+		.bind(chat.silent_minor)
+		.execute(&self.pool)
+		.await
 	}
 
 	pub async fn delete_chat(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
@@ -26,10 +74,56 @@ impl Connection {
 	}
 
 	pub async fn select_chat(&self, chat_id: i64) -> Result<Option<Chat>, Error> {
-		sqlx::query_as("SELECT id, lang FROM chat WHERE id = $1").bind(chat_id).fetch_optional(&self.pool).await
+		sqlx::query_as(
+			"SELECT id, lang, pin_warning, pinned_message_id, quiet_start, quiet_end, silent_minor, snooze_until \
+			 FROM chat WHERE id = $1",
+		)
+		.bind(chat_id)
+		.fetch_optional(&self.pool)
+		.await
 	}
 
 	pub async fn update_chat(&self, chat: &Chat) -> Result<PgQueryResult, Error> {
-		sqlx::query("UPDATE chat SET lang = $1 WHERE id = $2").bind(&chat.lang).bind(chat.id).execute(&self.pool).await
+		sqlx::query("UPDATE chat SET lang = $1, pin_warning = $2, silent_minor = $3 WHERE id = $4")
+			.bind(&chat.lang)
+			.bind(chat.pin_warning)
+			.bind(chat.silent_minor)
+			.bind(chat.id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn update_chat_pinned_message(
+		&self,
+		chat_id: i64,
+		message_id: Option<i32>,
+	) -> Result<PgQueryResult, Error> {
+		sqlx::query("UPDATE chat SET pinned_message_id = $1 WHERE id = $2")
+			.bind(message_id)
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn update_chat_quiet_hours(
+		&self,
+		chat_id: i64,
+		start: Option<NaiveTime>,
+		end: Option<NaiveTime>,
+	) -> Result<PgQueryResult, Error> {
+		sqlx::query("UPDATE chat SET quiet_start = $1, quiet_end = $2 WHERE id = $3")
+			.bind(start)
+			.bind(end)
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn update_chat_snooze(&self, chat_id: i64, until: Option<DateTime<Utc>>) -> Result<PgQueryResult, Error> {
+		sqlx::query("UPDATE chat SET snooze_until = $1 WHERE id = $2")
+			.bind(until)
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
 	}
 }
@@ -0,0 +1,55 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat};
+
+// Notifications held back from a chat, to be delivered together later.
+//
+// CREATE TABLE digest (
+//     id bigserial PRIMARY KEY,
+//     chat_id bigint NOT NULL
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE,
+//     text_zh text NOT NULL,
+//     text_en text NOT NULL
+// );
+#[derive(Clone, FromRow)]
+pub struct Digest {
+	pub id: i64,
+	pub text_zh: String,
+	pub text_en: String,
+}
+
+impl Connection {
+	pub async fn insert_digest(&self, chat_id: i64, text_zh: &str, text_en: &str) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO digest (chat_id, text_zh, text_en) VALUES ($1, $2, $3)")
+			.bind(chat_id)
+			.bind(text_zh)
+			.bind(text_en)
+			.execute(&self.pool)
+			.await
+	}
+
+	/// Removes and returns the held notifications of a chat, oldest first.
+	pub async fn take_digests(&self, chat_id: i64) -> Result<Vec<Digest>, Error> {
+		let mut digests: Vec<Digest> =
+			sqlx::query_as("DELETE FROM digest WHERE chat_id = $1 RETURNING id, text_zh, text_en")
+				.bind(chat_id)
+				.fetch_all(&self.pool)
+				.await?;
+		digests.sort_by_key(|d| d.id);
+
+		Ok(digests)
+	}
+
+	pub async fn select_digest_chats(&self) -> Result<Vec<Chat>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until FROM chat WHERE EXISTS (SELECT 1 FROM digest WHERE digest.chat_id = \
+			 chat.id)",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat};
+
+// CREATE TABLE keyword (
+//     chat_id bigint NOT NULL
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE,
+//     keyword text NOT NULL,
+//     PRIMARY KEY (chat_id, keyword)
+// );
+#[derive(Clone, FromRow)]
+pub struct Keyword {
+	pub chat_id: i64,
+	pub keyword: String,
+}
+
+impl Connection {
+	pub async fn insert_keyword(&self, chat_id: i64, keyword: &str) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO keyword (chat_id, keyword) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+			.bind(chat_id)
+			.bind(keyword)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_keyword(&self, chat_id: i64, keyword: &str) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM keyword WHERE chat_id = $1 AND keyword = $2")
+			.bind(chat_id)
+			.bind(keyword)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_keywords(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM keyword WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_keywords(&self, chat_id: i64) -> Result<Vec<String>, Error> {
+		sqlx::query_scalar("SELECT keyword FROM keyword WHERE chat_id = $1 ORDER BY keyword")
+			.bind(chat_id)
+			.fetch_all(&self.pool)
+			.await
+	}
+
+	pub async fn select_all_keywords(&self) -> Result<Vec<Keyword>, Error> {
+		sqlx::query_as("SELECT chat_id, keyword FROM keyword").fetch_all(&self.pool).await
+	}
+
+	/// Chats that registered keywords but are not subscribed, which are only
+	/// delivered what mentions the keywords. Keywords of the subscribed chats
+	/// narrow down what is delivered to them in the same way.
+	pub async fn select_keyword_chats(&self) -> Result<Vec<Chat>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until FROM chat WHERE EXISTS (SELECT 1 FROM keyword WHERE \
+			 keyword.chat_id = chat.id) AND NOT EXISTS (SELECT 1 FROM subscription WHERE subscription.chat_id = \
+			 chat.id)",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
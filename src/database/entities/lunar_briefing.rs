@@ -0,0 +1,32 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, postgres::PgQueryResult};
+
+use crate::database::Connection;
+
+// Chats appending the lunar date to the local weather forecast.
+//
+// CREATE TABLE lunar_briefing (
+//     chat_id bigint PRIMARY KEY
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE
+// );
+impl Connection {
+	pub async fn insert_lunar_briefing(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO lunar_briefing (chat_id) VALUES ($1) ON CONFLICT DO NOTHING")
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_lunar_briefing(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM lunar_briefing WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_lunar_briefing(&self, chat_id: i64) -> Result<bool, Error> {
+		sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM lunar_briefing WHERE chat_id = $1)")
+			.bind(chat_id)
+			.fetch_one(&self.pool)
+			.await
+	}
+}
@@ -1,4 +1,18 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
+pub mod channel;
 pub mod chat;
+pub mod digest;
+pub mod earthquake_alert;
+pub mod keyword;
+pub mod lunar_briefing;
+pub mod notice;
+pub mod psr;
+pub mod rain_alert;
+pub mod schedule;
+pub mod subscription;
+pub mod threshold;
+pub mod warning_delivery;
+pub mod warning_filter;
+pub mod warning_issuance;
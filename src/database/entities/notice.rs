@@ -0,0 +1,70 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use chrono::{DateTime, Utc};
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, types::notice_kind::NoticeKind};
+
+// Notices that have been pushed and are still present in the fetched data,
+// keyed by the digest of the texts as long tips do not fit in an index.
+//
+// CREATE TABLE notice (
+//     kind notice_kind NOT NULL,
+//     digest bytea NOT NULL,
+//     text_zh text NOT NULL,
+//     text_en text NOT NULL,
+//     pushed_time timestamptz NOT NULL DEFAULT now(),
+//     PRIMARY KEY (kind, digest)
+// );
+#[derive(Clone, FromRow)]
+pub struct Notice {
+	pub kind: NoticeKind,
+	pub text_zh: String,
+	pub text_en: String,
+}
+
+impl Connection {
+	pub async fn insert_notice(&self, notice: &Notice) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"INSERT INTO notice (kind, digest, text_zh, text_en) VALUES ($1, sha256(convert_to($2, 'UTF8')) || \
+			 sha256(convert_to($3, 'UTF8')), $2, $3) ON CONFLICT DO NOTHING",
+		)
+		.bind(notice.kind)
+		.bind(&notice.text_zh)
+		.bind(&notice.text_en)
+		.execute(&self.pool)
+		.await
+	}
+
+	pub async fn delete_notice(&self, notice: &Notice) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"DELETE FROM notice WHERE kind = $1 AND digest = sha256(convert_to($2, 'UTF8')) || \
+			 sha256(convert_to($3, 'UTF8'))",
+		)
+		.bind(notice.kind)
+		.bind(&notice.text_zh)
+		.bind(&notice.text_en)
+		.execute(&self.pool)
+		.await
+	}
+
+	pub async fn delete_notices_pushed_before(
+		&self,
+		kind: NoticeKind,
+		time: DateTime<Utc>,
+	) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM notice WHERE kind = $1 AND pushed_time < $2")
+			.bind(kind)
+			.bind(time)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn select_notices(&self, kind: NoticeKind) -> Result<Vec<Notice>, Error> {
+		sqlx::query_as("SELECT kind, text_zh, text_en FROM notice WHERE kind = $1")
+			.bind(kind)
+			.fetch_all(&self.pool)
+			.await
+	}
+}
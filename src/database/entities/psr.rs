@@ -0,0 +1,31 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use chrono::NaiveDate;
+use sqlx::{Error, postgres::PgQueryResult};
+
+use crate::database::Connection;
+
+// The last known probability of significant rain of each forecast day.
+//
+// CREATE TABLE psr (
+//     date date PRIMARY KEY,
+//     level smallint NOT NULL
+// );
+impl Connection {
+	pub async fn select_psr(&self, date: NaiveDate) -> Result<Option<i16>, Error> {
+		sqlx::query_scalar("SELECT level FROM psr WHERE date = $1").bind(date).fetch_optional(&self.pool).await
+	}
+
+	pub async fn upsert_psr(&self, date: NaiveDate, level: i16) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO psr (date, level) VALUES ($1, $2) ON CONFLICT (date) DO UPDATE SET level = $2")
+			.bind(date)
+			.bind(level)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_psr_before(&self, date: NaiveDate) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM psr WHERE date < $1").bind(date).execute(&self.pool).await
+	}
+}
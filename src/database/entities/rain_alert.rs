@@ -0,0 +1,39 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat};
+
+// CREATE TABLE rain_alert (
+//     chat_id bigint PRIMARY KEY
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE
+// );
+impl Connection {
+	pub async fn insert_rain_alert(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO rain_alert (chat_id) VALUES ($1) ON CONFLICT DO NOTHING")
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_rain_alert(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM rain_alert WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_rain_alert(&self, chat_id: i64) -> Result<bool, Error> {
+		sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM rain_alert WHERE chat_id = $1)")
+			.bind(chat_id)
+			.fetch_one(&self.pool)
+			.await
+	}
+
+	pub async fn select_rain_alert_chats(&self) -> Result<Vec<Chat>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until FROM chat JOIN rain_alert ON rain_alert.chat_id = chat.id",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use chrono::NaiveTime;
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat, types::product::Product};
+
+// CREATE TABLE schedule (
+//     chat_id bigint NOT NULL
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE,
+//     time time NOT NULL,
+//     product product NOT NULL,
+//     PRIMARY KEY (chat_id, time, product)
+// );
+#[derive(Clone, FromRow)]
+pub struct Schedule {
+	pub chat_id: i64,
+	pub time: NaiveTime,
+	pub product: Product,
+}
+
+#[derive(Clone, FromRow)]
+pub struct DueSchedule {
+	#[sqlx(flatten)]
+	pub chat: Chat,
+	pub product: Product,
+}
+
+impl Connection {
+	pub async fn insert_schedule(&self, schedule: &Schedule) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO schedule (chat_id, time, product) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING")
+			.bind(schedule.chat_id)
+			.bind(schedule.time)
+			.bind(schedule.product)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_schedule(&self, chat_id: i64, time: NaiveTime) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM schedule WHERE chat_id = $1 AND time = $2")
+			.bind(chat_id)
+			.bind(time)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_schedules(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM schedule WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_schedules(&self, chat_id: i64) -> Result<Vec<Schedule>, Error> {
+		sqlx::query_as("SELECT chat_id, time, product FROM schedule WHERE chat_id = $1 ORDER BY time, product")
+			.bind(chat_id)
+			.fetch_all(&self.pool)
+			.await
+	}
+
+	pub async fn select_due_schedules(&self, time: NaiveTime) -> Result<Vec<DueSchedule>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until, schedule.product FROM schedule JOIN chat ON chat.id = \
+			 schedule.chat_id WHERE schedule.time = $1",
+		)
+		.bind(time)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,32 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat};
+
+// CREATE TABLE subscription (
+//     chat_id bigint PRIMARY KEY
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE
+// );
+impl Connection {
+	pub async fn insert_subscription(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO subscription (chat_id) VALUES ($1) ON CONFLICT DO NOTHING")
+			.bind(chat_id)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_subscription(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM subscription WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_subscribed_chats(&self) -> Result<Vec<Chat>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until FROM chat JOIN subscription ON subscription.chat_id = chat.id",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::{Connection, entities::chat::Chat, types::metric::Metric};
+
+// CREATE TABLE threshold (
+//     chat_id bigint NOT NULL
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE,
+//     metric metric NOT NULL,
+//     above boolean NOT NULL,
+//     value real NOT NULL,
+//     triggered boolean NOT NULL DEFAULT false,
+//     PRIMARY KEY (chat_id, metric, above)
+// );
+#[derive(Clone, FromRow)]
+pub struct Threshold {
+	pub chat_id: i64,
+	pub metric: Metric,
+	pub above: bool,
+	pub value: f32,
+	pub triggered: bool,
+}
+
+#[derive(Clone, FromRow)]
+pub struct ChatThreshold {
+	#[sqlx(flatten)]
+	pub chat: Chat,
+	#[sqlx(flatten)]
+	pub threshold: Threshold,
+}
+
+impl Connection {
+	pub async fn insert_threshold(&self, threshold: &Threshold) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"INSERT INTO threshold (chat_id, metric, above, value, triggered) VALUES ($1, $2, $3, $4, $5) ON \
+			 CONFLICT (chat_id, metric, above) DO UPDATE SET value = EXCLUDED.value, triggered = EXCLUDED.triggered",
+		)
+		.bind(threshold.chat_id)
+		.bind(threshold.metric)
+		.bind(threshold.above)
+		.bind(threshold.value)
+		.bind(threshold.triggered)
+		.execute(&self.pool)
+		.await
+	}
+
+	pub async fn delete_threshold(&self, chat_id: i64, metric: Metric) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM threshold WHERE chat_id = $1 AND metric = $2")
+			.bind(chat_id)
+			.bind(metric)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_thresholds(&self, chat_id: i64) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM threshold WHERE chat_id = $1").bind(chat_id).execute(&self.pool).await
+	}
+
+	pub async fn select_thresholds(&self, chat_id: i64) -> Result<Vec<Threshold>, Error> {
+		sqlx::query_as(
+			"SELECT chat_id, metric, above, value, triggered FROM threshold WHERE chat_id = $1 ORDER BY metric, \
+			 above",
+		)
+		.bind(chat_id)
+		.fetch_all(&self.pool)
+		.await
+	}
+
+	pub async fn select_chat_thresholds(&self) -> Result<Vec<ChatThreshold>, Error> {
+		sqlx::query_as(
+			"SELECT chat.id, chat.lang, chat.pin_warning, chat.pinned_message_id, chat.quiet_start, chat.quiet_end, \
+			 chat.silent_minor, chat.snooze_until, threshold.chat_id, threshold.metric, threshold.above, \
+			 threshold.value, threshold.triggered FROM threshold JOIN chat ON chat.id = threshold.chat_id",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+
+	pub async fn update_threshold_triggered(&self, threshold: &Threshold) -> Result<PgQueryResult, Error> {
+		sqlx::query("UPDATE threshold SET triggered = $4 WHERE chat_id = $1 AND metric = $2 AND above = $3")
+			.bind(threshold.chat_id)
+			.bind(threshold.metric)
+			.bind(threshold.above)
+			.bind(threshold.triggered)
+			.execute(&self.pool)
+			.await
+	}
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use chrono::{DateTime, Utc};
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::Connection;
+
+// CREATE TABLE warning_delivery (
+//     chat_id bigint NOT NULL,
+//     code text NOT NULL,
+//     subtype text NOT NULL,
+//     update_time timestamptz NOT NULL,
+//     name_zh text NOT NULL,
+//     name_en text NOT NULL,
+//     message_ids integer[] NOT NULL DEFAULT '{}',
+//     PRIMARY KEY (chat_id, code, subtype)
+// );
+#[derive(Clone, FromRow)]
+pub struct WarningDelivery {
+	pub chat_id: i64,
+	pub code: String,
+	pub subtype: String,
+	pub update_time: DateTime<Utc>,
+	pub name_zh: String,
+	pub name_en: String,
+	pub message_ids: Vec<i32>,
+}
+
+impl Connection {
+	pub async fn upsert_warning_delivery(&self, delivery: &WarningDelivery) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"INSERT INTO warning_delivery (chat_id, code, subtype, update_time, name_zh, name_en, message_ids) VALUES \
+			 ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (chat_id, code, subtype) DO UPDATE SET update_time = \
+			 EXCLUDED.update_time, name_zh = EXCLUDED.name_zh, name_en = EXCLUDED.name_en, message_ids = \
+			 EXCLUDED.message_ids",
+		)
+		.bind(delivery.chat_id)
+		.bind(&delivery.code)
+		.bind(&delivery.subtype)
+		.bind(delivery.update_time)
+		.bind(&delivery.name_zh)
+		.bind(&delivery.name_en)
+		.bind(&delivery.message_ids)
+		.execute(&self.pool)
+		.await
+	}
+
+	pub async fn delete_warning_delivery(&self, delivery: &WarningDelivery) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM warning_delivery WHERE chat_id = $1 AND code = $2 AND subtype = $3")
+			.bind(delivery.chat_id)
+			.bind(&delivery.code)
+			.bind(&delivery.subtype)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn select_warning_deliveries(&self) -> Result<Vec<WarningDelivery>, Error> {
+		sqlx::query_as(
+			"SELECT chat_id, code, subtype, update_time, name_zh, name_en, message_ids FROM warning_delivery",
+		)
+		.fetch_all(&self.pool)
+		.await
+	}
+
+	pub async fn select_chat_warning_deliveries(&self, chat_id: i64) -> Result<Vec<WarningDelivery>, Error> {
+		sqlx::query_as(
+			"SELECT chat_id, code, subtype, update_time, name_zh, name_en, message_ids FROM warning_delivery WHERE \
+			 chat_id = $1",
+		)
+		.bind(chat_id)
+		.fetch_all(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::Connection;
+
+// Warning codes a chat does not want to receive.
+//
+// CREATE TABLE warning_filter (
+//     chat_id bigint NOT NULL
+//         REFERENCES chat (id) ON DELETE CASCADE ON UPDATE CASCADE,
+//     code text NOT NULL,
+//     PRIMARY KEY (chat_id, code)
+// );
+#[derive(Clone, FromRow)]
+pub struct WarningFilter {
+	pub chat_id: i64,
+	pub code: String,
+}
+
+impl Connection {
+	pub async fn insert_warning_filter(&self, chat_id: i64, code: &str) -> Result<PgQueryResult, Error> {
+		sqlx::query("INSERT INTO warning_filter (chat_id, code) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+			.bind(chat_id)
+			.bind(code)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn delete_warning_filter(&self, chat_id: i64, code: &str) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM warning_filter WHERE chat_id = $1 AND code = $2")
+			.bind(chat_id)
+			.bind(code)
+			.execute(&self.pool)
+			.await
+	}
+
+	pub async fn select_warning_filters(&self, chat_id: i64) -> Result<Vec<String>, Error> {
+		sqlx::query_scalar("SELECT code FROM warning_filter WHERE chat_id = $1")
+			.bind(chat_id)
+			.fetch_all(&self.pool)
+			.await
+	}
+
+	pub async fn select_all_warning_filters(&self) -> Result<Vec<WarningFilter>, Error> {
+		sqlx::query_as("SELECT chat_id, code FROM warning_filter").fetch_all(&self.pool).await
+	}
+}
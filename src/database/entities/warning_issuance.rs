@@ -0,0 +1,93 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use chrono::{DateTime, Utc};
+use sqlx::{Error, FromRow, postgres::PgQueryResult};
+
+use crate::database::Connection;
+
+// The contents of each issuance of the warnings in force, to compare a
+// re-issued warning with its previous issuance.
+//
+// CREATE TABLE warning_issuance (
+//     code text NOT NULL,
+//     subtype text NOT NULL,
+//     update_time timestamptz NOT NULL,
+//     contents_zh text[] NOT NULL,
+//     contents_en text[] NOT NULL,
+//     PRIMARY KEY (code, subtype, update_time)
+// );
+#[derive(Clone, FromRow)]
+pub struct WarningIssuance {
+	pub code: String,
+	pub subtype: String,
+	pub update_time: DateTime<Utc>,
+	pub contents_zh: Vec<String>,
+	pub contents_en: Vec<String>,
+}
+
+impl Connection {
+	pub async fn insert_warning_issuance(&self, issuance: &WarningIssuance) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"INSERT INTO warning_issuance (code, subtype, update_time, contents_zh, contents_en) VALUES ($1, $2, $3, \
+			 $4, $5) ON CONFLICT (code, subtype, update_time) DO NOTHING",
+		)
+		.bind(&issuance.code)
+		.bind(&issuance.subtype)
+		.bind(issuance.update_time)
+		.bind(&issuance.contents_zh)
+		.bind(&issuance.contents_en)
+		.execute(&self.pool)
+		.await
+	}
+
+	/// Selects the latest issuance of the warning before the given time.
+	pub async fn select_previous_warning_issuance(
+		&self,
+		code: &str,
+		subtype: &str,
+		update_time: DateTime<Utc>,
+	) -> Result<Option<WarningIssuance>, Error> {
+		sqlx::query_as(
+			"SELECT code, subtype, update_time, contents_zh, contents_en FROM warning_issuance WHERE code = $1 AND \
+			 subtype = $2 AND update_time < $3 ORDER BY update_time DESC LIMIT 1",
+		)
+		.bind(code)
+		.bind(subtype)
+		.bind(update_time)
+		.fetch_optional(&self.pool)
+		.await
+	}
+
+	/// Deletes the issuances before the given time of the warning.
+	pub async fn delete_warning_issuances_before(
+		&self,
+		code: &str,
+		subtype: &str,
+		update_time: DateTime<Utc>,
+	) -> Result<PgQueryResult, Error> {
+		sqlx::query("DELETE FROM warning_issuance WHERE code = $1 AND subtype = $2 AND update_time < $3")
+			.bind(code)
+			.bind(subtype)
+			.bind(update_time)
+			.execute(&self.pool)
+			.await
+	}
+
+	/// Deletes the issuances of warnings no longer in force, given as parallel
+	/// lists of codes and subtypes.
+	pub async fn delete_inactive_warning_issuances(
+		&self,
+		codes: &[String],
+		subtypes: &[String],
+	) -> Result<PgQueryResult, Error> {
+		sqlx::query(
+			"DELETE FROM warning_issuance WHERE (code, subtype) NOT IN (SELECT * FROM UNNEST($1::text[], \
+			 $2::text[]))",
+		)
+		.bind(codes)
+		.bind(subtypes)
+		.execute(&self.pool)
+		.await
+	}
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::Type;
+use strum::EnumString;
+
+use crate::database::types::lang::Lang;
+
+// CREATE TYPE metric AS ENUM ('Temperature', 'Humidity', 'UVIndex');
+#[derive(Clone, Copy, Debug, EnumString, Eq, Type, PartialEq)]
+#[sqlx(type_name = "metric")]
+#[strum(ascii_case_insensitive)]
+pub enum Metric {
+	#[strum(serialize = "temperature", serialize = "temp")]
+	Temperature,
+	#[strum(serialize = "humidity", serialize = "rh")]
+	Humidity,
+	#[strum(serialize = "uvindex", serialize = "uv")]
+	UVIndex,
+}
+
+impl Metric {
+	pub fn name(self, lang: &Lang) -> &'static str {
+		match self {
+			Self::Temperature => lang.map("氣溫 Temperature", "氣溫", "Temperature"),
+			Self::Humidity => lang.map("相對濕度 Relative humidity", "相對濕度", "Relative humidity"),
+			Self::UVIndex => lang.map("紫外線指數 UV index", "紫外線指數", "UV index"),
+		}
+	}
+
+	pub const fn unit(self) -> &'static str {
+		match self {
+			Self::Temperature => "°C",
+			Self::Humidity => "%",
+			Self::UVIndex => "",
+		}
+	}
+
+	/// How far a reading has to fall back before an alert can fire again.
+	pub const fn margin(self) -> f32 {
+		match self {
+			Self::Temperature | Self::UVIndex => 1.0,
+			Self::Humidity => 5.0,
+		}
+	}
+}
@@ -1,4 +1,7 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 pub mod lang;
+pub mod metric;
+pub mod notice_kind;
+pub mod product;
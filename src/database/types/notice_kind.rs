@@ -0,0 +1,15 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::Type;
+
+// CREATE TYPE notice_kind AS ENUM ('SpecialTip', 'RainstormReminder',
+// 'FireDanger', 'FeltEarthquake');
+#[derive(Clone, Copy, Debug, Eq, Type, PartialEq)]
+#[sqlx(type_name = "notice_kind")]
+pub enum NoticeKind {
+	SpecialTip,
+	RainstormReminder,
+	FireDanger,
+	FeltEarthquake,
+}
@@ -0,0 +1,29 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use sqlx::Type;
+use strum::EnumString;
+
+use crate::{database::types::lang::Lang, statics};
+
+// CREATE TYPE product AS ENUM ('Briefing', 'Bulletin', 'Forecast');
+#[derive(Clone, Copy, Debug, EnumString, Eq, Type, PartialEq)]
+#[sqlx(type_name = "product")]
+#[strum(ascii_case_insensitive)]
+pub enum Product {
+	Briefing,
+	Bulletin,
+	Forecast,
+}
+
+impl Product {
+	pub fn title(self, lang: &Lang) -> String {
+		let (zh, en) = match self {
+			Self::Briefing => (statics::BRIEFING_TITLE_CHINESE, statics::BRIEFING_TITLE_ENGLISH),
+			Self::Bulletin => (statics::BULLETIN_TITLE_CHINESE, statics::BULLETIN_TITLE_ENGLISH),
+			Self::Forecast => (statics::FORECAST_TITLE_CHINESE, statics::FORECAST_TITLE_ENGLISH),
+		};
+
+		lang.map(format!("{zh} {en}"), zh.to_string(), en.to_string())
+	}
+}
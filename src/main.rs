@@ -11,9 +11,12 @@ async fn main() {
 	log::info!("{NAME_VERSION_STRING}");
 
 	let db = database::connect(args.db_uri).await;
-	let mut tg = telegram::connect(args.bot, db);
+	db.register_channels(&args.channels).await;
+	trigger::set_edit_warnings(args.edit_warnings);
 
-	tokio::join!(weather::update(), tg.dispatch());
+	let mut tg = telegram::connect(args.bot, args.admins, args.require_start, db);
+
+	tokio::join!(weather::update(), trigger::schedule(), tg.dispatch());
 }
 
 mod answer;
@@ -21,6 +24,7 @@ mod args;
 mod config;
 mod database;
 mod http;
+mod outbound;
 mod statics;
 mod telegram;
 mod tool;
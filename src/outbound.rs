@@ -0,0 +1,191 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::{LazyLock, OnceLock},
+};
+
+use teloxide::{ApiError, RequestError, prelude::*, requests::Output};
+use tokio::{
+	sync::Mutex,
+	time::{Duration, Instant, sleep, sleep_until},
+};
+
+use crate::database::Connection;
+
+const MAX_ATTEMPTS: u32 = 5;
+
+// Telegram allows about 30 messages per second in total, 20 messages per
+// minute in the same group and 1 message per second in the same private chat.
+const GLOBAL_LIMIT: (usize, Duration) = (30, Duration::from_secs(1));
+const GROUP_LIMIT: (usize, Duration) = (20, Duration::from_secs(60));
+const PRIVATE_LIMIT: (usize, Duration) = (1, Duration::from_secs(1));
+
+static LIMITER: LazyLock<Mutex<Limiter>> = LazyLock::new(|| Mutex::new(Limiter::default()));
+static DB: OnceLock<Connection> = OnceLock::new();
+
+#[derive(Default)]
+struct Limiter {
+	global: VecDeque<Instant>,
+	chats: HashMap<ChatId, VecDeque<Instant>>,
+}
+
+impl Limiter {
+	/// Returns the instant when the next message may be sent, or [`None`] if it
+	/// may be sent right now.
+	fn next_slot(sent: &mut VecDeque<Instant>, (limit, period): (usize, Duration), now: Instant) -> Option<Instant> {
+		while sent.front().is_some_and(|&t| now.duration_since(t) >= period) {
+			sent.pop_front();
+		}
+
+		(sent.len() >= limit).then(|| sent.front().copied().unwrap_or(now) + period)
+	}
+
+	fn acquire(&mut self, chat_id: ChatId) -> Option<Instant> {
+		let now = Instant::now();
+		let chat_limit = if chat_id.is_user() { PRIVATE_LIMIT } else { GROUP_LIMIT };
+
+		let global = Self::next_slot(&mut self.global, GLOBAL_LIMIT, now);
+		let chat = Self::next_slot(self.chats.entry(chat_id).or_default(), chat_limit, now);
+
+		if let Some(next) = global.max(chat) {
+			return Some(next);
+		}
+
+		self.global.push_back(now);
+		self.chats.entry(chat_id).or_default().push_back(now);
+		self.chats.retain(|_, sent| sent.back().is_some_and(|&t| now.duration_since(t) < GROUP_LIMIT.1));
+
+		None
+	}
+}
+
+pub fn set_db(db_conn: Connection) {
+	let _ = DB.set(db_conn);
+}
+
+/// Whether the error means the chat is gone for good, e.g. the user blocked
+/// the bot or the group was deleted.
+const fn is_chat_gone(e: &RequestError) -> bool {
+	matches!(
+		e,
+		RequestError::Api(
+			ApiError::BotBlocked
+				| ApiError::BotKicked
+				| ApiError::BotKickedFromChannel
+				| ApiError::BotKickedFromSupergroup
+				| ApiError::ChatNotFound
+				| ApiError::GroupDeactivated
+				| ApiError::UserDeactivated
+		)
+	)
+}
+
+pub async fn migrate_chat(from: ChatId, to: ChatId) {
+	let Some(db_conn) = DB.get() else {
+		return;
+	};
+
+	log::info!("chat {from} has been migrated to {to}");
+
+	if let Err(e) = db_conn.migrate_chat(from.0, to.0).await {
+		log::error!("{e}");
+	}
+}
+
+pub async fn forget_chat(chat_id: ChatId) {
+	let Some(db_conn) = DB.get() else {
+		return;
+	};
+
+	log::info!("chat {chat_id} is no longer reachable, forgetting it");
+
+	if let Err(e) = db_conn.forget_chat(chat_id.0).await {
+		log::error!("{e}");
+	}
+}
+
+async fn acquire(chat_id: ChatId) {
+	loop {
+		// Do not hold the lock while waiting, so that a busy chat does not block
+		// the others.
+		let next = LIMITER.lock().await.acquire(chat_id);
+
+		match next {
+			Some(next) => sleep_until(next).await,
+			None => break,
+		}
+	}
+}
+
+/// Sends the request to the chat, throttled to stay within the limits of the
+/// Bot API.
+///
+/// Flood control and transient network errors are retried, while any other
+/// error is logged and returned. Chats that are no longer reachable are
+/// removed from the database, and groups upgraded to supergroups are moved,
+/// with the error returned for the caller to retry in the supergroup.
+pub async fn send<R>(chat_id: ChatId, request: R) -> Result<Output<R>, RequestError>
+where
+	R: Request<Err = RequestError>,
+{
+	let mut attempt = 0;
+
+	loop {
+		attempt += 1;
+		acquire(chat_id).await;
+
+		let e = match request.send_ref().await {
+			Ok(output) => return Ok(output),
+			Err(e) => e,
+		};
+
+		match &e {
+			RequestError::RetryAfter(secs) if attempt < MAX_ATTEMPTS => {
+				log::warn!("flood control exceeded in {chat_id}, retry after {} seconds", secs.seconds());
+				sleep(secs.duration()).await;
+			}
+			RequestError::Network(_) | RequestError::Io(_) if attempt < MAX_ATTEMPTS => {
+				log::warn!("failed to send request to {chat_id}, retrying: {e}");
+				sleep(Duration::from_secs(1 << attempt)).await;
+			}
+			_ => {
+				log::error!("failed to send request to {chat_id}: {e}");
+
+				if let RequestError::MigrateToChatId(to) = e {
+					migrate_chat(chat_id, to).await;
+				} else if is_chat_gone(&e) {
+					forget_chat(chat_id).await;
+				}
+
+				return Err(e);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use super::{Duration, GROUP_LIMIT, Instant, Limiter, PRIVATE_LIMIT};
+		use std::collections::VecDeque;
+
+		let now = Instant::now();
+		let mut sent = VecDeque::new();
+
+		assert_eq!(Limiter::next_slot(&mut sent, PRIVATE_LIMIT, now), None);
+
+		sent.push_back(now);
+		assert_eq!(Limiter::next_slot(&mut sent, PRIVATE_LIMIT, now), Some(now + PRIVATE_LIMIT.1));
+		assert_eq!(Limiter::next_slot(&mut sent, PRIVATE_LIMIT, now + Duration::from_secs(1)), None);
+		assert!(sent.is_empty());
+
+		let mut sent = (0..20).map(|i| now + Duration::from_secs(i)).collect::<VecDeque<_>>();
+		let later = now + Duration::from_secs(30);
+		assert_eq!(Limiter::next_slot(&mut sent, GROUP_LIMIT, later), Some(now + GROUP_LIMIT.1));
+		assert_eq!(Limiter::next_slot(&mut sent, GROUP_LIMIT, now + GROUP_LIMIT.1), None);
+		assert_eq!(sent.len(), 19);
+	}
+}
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 #![allow(dead_code)]
@@ -58,7 +58,22 @@ This bot provides weather information from Hong Kong Observatory.
 /briefing - 獲取本港地區天氣預報 Get local weather report
 /bulletin - 獲取當前天氣報吿 Get current weather report
 /forecast - 獲取九天天氣預報 Get 9-day weather forecast
-/warning - 獲取當前由天文台發出的天氣警報資料 Get the warning information from the Observatory";
+/warning - 獲取當前由天文台發出的天氣警報資料 Get the warning information from the Observatory
+/earthquake - 獲取地震資料，on/off 開關本地有感地震推送 Get earthquake information, on/off to toggle felt earthquake alerts
+/temp - 獲取各區氣溫，可指定測站 Get temperatures of all or a given station
+/rain - 獲取各區雨量，可指定地區 Get rainfall of all or a given district
+/tide - 獲取今明兩日潮汐預測 Get tide predictions of today and tomorrow
+/sun - 獲取今日日出日落時間 Get the times of sunrise and sunset today
+/moon - 獲取今日月出月落時間同月相 Get the times of moonrise and moonset and the moon phase today
+/lunar - 獲取農曆日期同節氣 Get the lunar date and solar term
+/subscribe - 訂閱天氣警告推送 Subscribe to weather warning notifications
+/unsubscribe - 取消訂閱天氣警告推送 Unsubscribe from weather warning notifications
+/schedule - 設定每日定時發送 Set up daily scheduled deliveries
+/threshold - 設定天氣讀數提示 Set up alerts on weather readings
+/rainalert - 開關明日降雨概率提示 Toggle alerts on the rain probability of tomorrow
+/quiet - 設定靜音時段 Set up quiet hours
+/snooze - 暫停自動推送 Snooze automatic messages
+/keyword - 設定警告關鍵字 Set up keywords for warnings";
 pub const HELP_MESSAGE_CHINESE: &str = r"此機械人將提供來自香港天文台的天氣資訊。
 
 /help - 查看本幫助訊息
@@ -67,7 +82,22 @@ pub const HELP_MESSAGE_CHINESE: &str = r"此機械人將提供來自香港天文
 /briefing - 獲取本港地區天氣預報
 /bulletin - 獲取當前天氣報吿
 /forecast - 獲取九天天氣預報
-/warning - 獲取當前由天文台發出的天氣警報資料";
+/warning - 獲取當前由天文台發出的天氣警報資料
+/earthquake - 獲取地震資料，on/off 開關本地有感地震推送
+/temp - 獲取各區氣溫，可指定測站
+/rain - 獲取各區雨量，可指定地區
+/tide - 獲取今明兩日潮汐預測
+/sun - 獲取今日日出日落時間
+/moon - 獲取今日月出月落時間同月相
+/lunar - 獲取農曆日期同節氣
+/subscribe - 訂閱天氣警告推送
+/unsubscribe - 取消訂閱天氣警告推送
+/schedule - 設定每日定時發送
+/threshold - 設定天氣讀數提示
+/rainalert - 開關明日降雨概率提示
+/quiet - 設定靜音時段
+/snooze - 暫停自動推送
+/keyword - 設定警告關鍵字";
 pub const HELP_MESSAGE_ENGLISH: &str = r"This bot provides weather information from Hong Kong Observatory.
 
 /help - Look for help
@@ -76,7 +106,22 @@ pub const HELP_MESSAGE_ENGLISH: &str = r"This bot provides weather information f
 /briefing - Get local weather forecast
 /bulletin - Get current weather report
 /forecast - Get 9-day weather forecast
-/warning - Get the warning information from the Observatory";
+/warning - Get the warning information from the Observatory
+/earthquake - Get earthquake information, on/off to toggle felt earthquake alerts
+/temp - Get temperatures of all or a given station
+/rain - Get rainfall of all or a given district
+/tide - Get tide predictions of today and tomorrow
+/sun - Get the times of sunrise and sunset today
+/moon - Get the times of moonrise and moonset and the moon phase today
+/lunar - Get the lunar date and solar term
+/subscribe - Subscribe to weather warning notifications
+/unsubscribe - Unsubscribe from weather warning notifications
+/schedule - Set up daily scheduled deliveries
+/threshold - Set up alerts on weather readings
+/rainalert - Toggle alerts on the rain probability of tomorrow
+/quiet - Set up quiet hours
+/snooze - Snooze automatic messages
+/keyword - Set up keywords for warnings";
 
 // Settings messages
 
@@ -98,6 +143,77 @@ pub const SETTINGS_MESSAGE_LANGUAGE_CHINESE: &str = r"語言";
 
 pub const SETTINGS_MESSAGE_LANGUAGE_ENGLISH: &str = r"Language";
 
+pub const SETTINGS_MESSAGE_PIN_WARNING_BILINGUAL: &str = r"置頂警告 Pin warnings";
+
+pub const SETTINGS_MESSAGE_PIN_WARNING_CHINESE: &str = r"置頂警告";
+
+pub const SETTINGS_MESSAGE_PIN_WARNING_ENGLISH: &str = r"Pin warnings";
+
+pub const SETTINGS_MESSAGE_WARNING_FILTER_BILINGUAL: &str = r"警告類型 Warning types";
+
+pub const SETTINGS_MESSAGE_WARNING_FILTER_CHINESE: &str = r"警告類型";
+
+pub const SETTINGS_MESSAGE_WARNING_FILTER_ENGLISH: &str = r"Warning types";
+
+pub const SETTINGS_MESSAGE_QUIET_HOURS_BILINGUAL: &str = r"靜音時段（香港時間）Quiet hours (Hong Kong Time)";
+
+pub const SETTINGS_MESSAGE_QUIET_HOURS_CHINESE: &str = r"靜音時段（香港時間）";
+
+pub const SETTINGS_MESSAGE_QUIET_HOURS_ENGLISH: &str = r"Quiet hours (Hong Kong Time)";
+
+pub const SETTINGS_MESSAGE_SILENT_BILINGUAL: &str = r"次要通知靜音 Silent minor notifications";
+
+pub const SETTINGS_MESSAGE_SILENT_CHINESE: &str = r"次要通知靜音";
+
+pub const SETTINGS_MESSAGE_SILENT_ENGLISH: &str = r"Silent minor notifications";
+
+pub const SETTINGS_BACK_BILINGUAL: &str = r"« 返回 Back";
+
+pub const SETTINGS_BACK_CHINESE: &str = r"« 返回";
+
+pub const SETTINGS_BACK_ENGLISH: &str = r"« Back";
+
+pub const SETTINGS_VALUE_ON_BILINGUAL: &str = r"開 On";
+
+pub const SETTINGS_VALUE_ON_CHINESE: &str = r"開";
+
+pub const SETTINGS_VALUE_ON_ENGLISH: &str = r"On";
+
+pub const SETTINGS_VALUE_OFF_BILINGUAL: &str = r"關 Off";
+
+pub const SETTINGS_VALUE_OFF_CHINESE: &str = r"關";
+
+pub const SETTINGS_VALUE_OFF_ENGLISH: &str = r"Off";
+
+// Warning filter messages
+
+make_bilingual!(
+	WARNING_FILTER_MESSAGE,
+	"請揀選你想接收嘅警告類型，點擊可以開關。",
+	"Choose the warning types you want to receive, click to toggle."
+);
+
+// Silent notification messages
+
+make_bilingual!(
+	SILENT_ON_MESSAGE,
+	"次要通知會靜音發送，八號或以上熱帶氣旋警告信號、黑色暴雨警告信號同海嘯警告照常響鬧。",
+	"Minor notifications will be sent silently. No. 8 or higher tropical cyclone signals, the Black Rainstorm \
+	 Warning and the Tsunami Warning still come with sound."
+);
+
+make_bilingual!(SILENT_OFF_MESSAGE, "所有通知都會響鬧。", "All notifications will come with sound.");
+
+// Pin warning messages
+
+make_bilingual!(
+	PIN_WARNING_ON_MESSAGE,
+	"而家開始我會置頂最嚴重嘅天氣警告。",
+	"The most severe weather warning will be pinned from now on."
+);
+
+make_bilingual!(PIN_WARNING_OFF_MESSAGE, "我唔會再置頂天氣警告。", "Weather warnings will no longer be pinned.");
+
 // Setlang messages
 
 make_bilingual!(
@@ -111,10 +227,270 @@ make_bilingual!(
 pub const SETLANG_QUESTION_BILINGUAL: &str = r"你想我用何語言發消息？
 What language do you want to receive messages in?";
 
+// Subscription messages
+
+make_bilingual!(SUBSCRIBE_MESSAGE, "而家開始我會向你推送天氣警告。", "You will receive weather warnings from now on.");
+
+make_bilingual!(
+	SUBSCRIBE_ALREADY_MESSAGE,
+	"你已經訂閱咗天氣警告推送。",
+	"You have already subscribed to weather warnings."
+);
+
+make_bilingual!(UNSUBSCRIBE_MESSAGE, "我唔會再向你推送天氣警告。", "You will no longer receive weather warnings.");
+
+make_bilingual!(
+	UNSUBSCRIBE_ALREADY_MESSAGE,
+	"你未有訂閱天氣警告推送。",
+	"You have not subscribed to weather warnings."
+);
+
+// Channel messages
+
+make_bilingual!(
+	CHANNEL_USAGE_MESSAGE,
+	"用法：/addchannel 頻道編號 [bilingual|chinese|english]\n或：/delchannel 頻道編號",
+	"Usage: /addchannel channel_id [bilingual|chinese|english]\nor: /delchannel channel_id"
+);
+
+make_bilingual!(CHANNEL_ADDED_MESSAGE, "頻道已登記。", "The channel has been registered.");
+
+make_bilingual!(
+	CHANNEL_REMOVED_MESSAGE,
+	"頻道已移除。如果頻道係用 -c 或 HKO_BOT_CHANNELS 設定嘅，請一併移除，否則下次啟動時會重新登記。",
+	"The channel has been removed. If it is given with -c or HKO_BOT_CHANNELS, also remove it there, or it is registered again at the next start."
+);
+
+make_bilingual!(CHANNEL_NOT_FOUND_MESSAGE, "搵唔到呢個頻道。", "The channel is not registered.");
+
+make_bilingual!(CHANNEL_LIST_TITLE, "<b>已登記頻道</b>", "<b>Registered channels</b>");
+
+make_bilingual!(CHANNEL_LIST_EMPTY_MESSAGE, "未有登記任何頻道。", "There is no registered channel.");
+
+// Schedule messages
+
+make_bilingual!(
+	SCHEDULE_USAGE_MESSAGE,
+	"用法：
+/schedule 07:30 [briefing|bulletin|forecast] - 每日喺指定時間（香港時間）發送
+/schedule remove 07:30 - 取消指定時間嘅發送
+/schedule clear - 取消所有定時發送",
+	"Usage:
+/schedule 07:30 [briefing|bulletin|forecast] - Deliver daily at the time (Hong Kong Time)
+/schedule remove 07:30 - Remove the deliveries at the time
+/schedule clear - Remove all scheduled deliveries"
+);
+
+make_bilingual!(SCHEDULE_ADDED_MESSAGE, "已加入定時發送。", "The scheduled delivery has been added.");
+
+make_bilingual!(SCHEDULE_REMOVED_MESSAGE, "已取消定時發送。", "The scheduled delivery has been removed.");
+
+make_bilingual!(SCHEDULE_NOT_FOUND_MESSAGE, "呢個時間未有定時發送。", "There is no scheduled delivery at the time.");
+
+make_bilingual!(SCHEDULE_CLEARED_MESSAGE, "已取消所有定時發送。", "All scheduled deliveries have been removed.");
+
+make_bilingual!(SCHEDULE_LIST_TITLE, "<b>定時發送（香港時間）</b>", "<b>Scheduled deliveries (Hong Kong Time)</b>");
+
+make_bilingual!(SCHEDULE_LIST_EMPTY_MESSAGE, "未有設定定時發送。", "There is no scheduled delivery.");
+
+// Rain alert messages
+
+make_bilingual!(
+	RAIN_ALERT_ON_MESSAGE,
+	"當明日嘅顯著降雨概率轉為高或者被調高，我會通知你。",
+	"You will be alerted when the probability of significant rain of tomorrow becomes high or is raised."
+);
+
+make_bilingual!(
+	RAIN_ALERT_OFF_MESSAGE,
+	"我唔會再通知你明日嘅降雨概率。",
+	"You will no longer be alerted on the rain probability of tomorrow."
+);
+
+// Earthquake messages
+
+make_bilingual!(NO_EARTHQUAKE_MESSAGE, "現時並無地震資料。", "There is no earthquake information.");
+
+make_bilingual!(
+	EARTHQUAKE_ALERT_ON_MESSAGE,
+	"當天文台發出本地有感地震報告，我會通知你。",
+	"You will be alerted when the Observatory reports a locally felt earthquake."
+);
+
+make_bilingual!(
+	EARTHQUAKE_ALERT_OFF_MESSAGE,
+	"我唔會再通知你本地有感地震。",
+	"You will no longer be alerted on locally felt earthquakes."
+);
+
+// District reading messages
+
+make_bilingual!(
+	TEMP_STATION_NOT_FOUND_MESSAGE,
+	"搵唔到呢個測站，請用 /temp 查看所有測站。",
+	"The station is not found, please check all stations with /temp."
+);
+
+make_bilingual!(
+	RAIN_DISTRICT_NOT_FOUND_MESSAGE,
+	"搵唔到呢個地區，請用 /rain 查看所有地區。",
+	"The district is not found, please check all districts with /rain."
+);
+
+// Tide messages
+
+make_bilingual!(
+	TIDE_STATION_NOT_FOUND_MESSAGE,
+	"搵唔到呢個測站，請用以下其中一個測站：",
+	"The station is not found, please use one of the stations below:"
+);
+
+// Lunar date messages
+
+make_bilingual!(
+	LUNAR_USAGE_MESSAGE,
+	"用法：
+/lunar - 獲取今日嘅農曆日期
+/lunar 2026-10-18 - 獲取指定日期嘅農曆日期（天文台提供嘅年份範圍內）
+/lunar on - 喺本港地區天氣預報加入農曆日期
+/lunar off - 唔再喺本港地區天氣預報加入農曆日期",
+	"Usage:
+/lunar - Get the lunar date of today
+/lunar 2026-10-18 - Get the lunar date of the date, within the years provided by the Observatory
+/lunar on - Append the lunar date to the local weather forecast, in Chinese only
+/lunar off - Stop appending the lunar date to the local weather forecast"
+);
+
+make_bilingual!(
+	LUNAR_BRIEFING_ON_MESSAGE,
+	"本港地區天氣預報會加入農曆日期。",
+	"The lunar date will be appended to the local weather forecast in Chinese."
+);
+
+make_bilingual!(
+	LUNAR_BRIEFING_OFF_MESSAGE,
+	"本港地區天氣預報唔會再加入農曆日期。",
+	"The lunar date will no longer be appended to the local weather forecast."
+);
+
+// Quiet hours messages
+
+make_bilingual!(
+	QUIET_USAGE_MESSAGE,
+	"用法：
+/quiet 23:00-07:00 - 喺指定時段（香港時間）暫緩推送次要警告，時段結束後一併發送
+/quiet off - 取消靜音時段
+
+八號或以上熱帶氣旋警告信號、黑色暴雨警告信號同海嘯警告照常即時推送。",
+	"Usage:
+/quiet 23:00-07:00 - Hold minor warnings during the hours (Hong Kong Time) and deliver them together afterwards
+/quiet off - Turn off quiet hours
+
+No. 8 or higher tropical cyclone signals, the Black Rainstorm Warning and the Tsunami Warning are always delivered immediately."
+);
+
+make_bilingual!(QUIET_ON_MESSAGE, "已設定靜音時段。", "Quiet hours have been set.");
+
+make_bilingual!(QUIET_OFF_MESSAGE, "已取消靜音時段。", "Quiet hours have been turned off.");
+
+// Snooze messages
+
+make_bilingual!(
+	SNOOZE_USAGE_MESSAGE,
+	"用法：
+/snooze 2h - 暫停所有自動推送兩個鐘
+/snooze tomorrow - 暫停至明早七點（香港時間）
+/snooze off - 恢復自動推送",
+	"Usage:
+/snooze 2h - Snooze all automatic messages for 2 hours
+/snooze tomorrow - Snooze until 07:00 tomorrow (Hong Kong Time)
+/snooze off - Resume automatic messages"
+);
+
+// Keyword messages
+
+make_bilingual!(
+	KEYWORD_USAGE_MESSAGE,
+	"用法：
+/keyword add 大嶼山 - 只推送提及關鍵字嘅警告同特別天氣提示
+/keyword remove 大嶼山 - 移除關鍵字
+/keyword clear - 移除所有關鍵字
+
+中英文關鍵字都會同時比對警告嘅中英文內容。",
+	"Usage:
+/keyword add Lantau - Only deliver warnings and special tips that mention the keyword
+/keyword remove Lantau - Remove the keyword
+/keyword clear - Remove all keywords
+
+Keywords in either language are matched against both the Chinese and English text."
+);
+
+make_bilingual!(
+	KEYWORD_ADDED_MESSAGE,
+	"已加入關鍵字。之後只會推送提及任何一個關鍵字嘅警告同特別天氣提示，已訂閱天氣警告都一樣。",
+	"The keyword has been added. Only warnings and special tips that mention any of the keywords are delivered from now on, even if the chat has subscribed to weather warnings."
+);
+
+make_bilingual!(KEYWORD_REMOVED_MESSAGE, "已移除關鍵字。", "The keyword has been removed.");
+
+make_bilingual!(KEYWORD_NOT_FOUND_MESSAGE, "未有設定呢個關鍵字。", "The keyword is not registered.");
+
+make_bilingual!(KEYWORD_CLEARED_MESSAGE, "已移除所有關鍵字。", "All keywords have been removed.");
+
+make_bilingual!(KEYWORD_LIST_TITLE, "<b>警告關鍵字</b>", "<b>Keywords for warnings</b>");
+
+make_bilingual!(KEYWORD_LIST_EMPTY_MESSAGE, "未有設定警告關鍵字。", "There is no keyword for warnings.");
+
+// Threshold messages
+
+make_bilingual!(
+	THRESHOLD_USAGE_MESSAGE,
+	"用法：
+/threshold temperature &gt;= 33 - 天文台氣溫達到 33°C 時提示
+/threshold humidity &lt;= 40 - 相對濕度跌至 40% 時提示
+/threshold uv &gt;= 8 - 紫外線指數達到 8 時提示
+/threshold remove temperature - 取消氣溫提示
+/threshold clear - 取消所有提示",
+	"Usage:
+/threshold temperature &gt;= 33 - Alert when the temperature reaches 33°C
+/threshold humidity &lt;= 40 - Alert when the relative humidity drops to 40%
+/threshold uv &gt;= 8 - Alert when the UV index reaches 8
+/threshold remove temperature - Remove the temperature alerts
+/threshold clear - Remove all alerts"
+);
+
+make_bilingual!(THRESHOLD_ADDED_MESSAGE, "已設定提示。", "The alert has been set.");
+
+make_bilingual!(THRESHOLD_REMOVED_MESSAGE, "已取消提示。", "The alert has been removed.");
+
+make_bilingual!(THRESHOLD_NOT_FOUND_MESSAGE, "未有設定呢項提示。", "There is no alert on the reading.");
+
+make_bilingual!(THRESHOLD_CLEARED_MESSAGE, "已取消所有提示。", "All alerts have been removed.");
+
+make_bilingual!(THRESHOLD_LIST_TITLE, "<b>天氣讀數提示</b>", "<b>Alerts on weather readings</b>");
+
+make_bilingual!(THRESHOLD_LIST_EMPTY_MESSAGE, "未有設定天氣讀數提示。", "There is no alert on weather readings.");
+
 // No warning messages
 
 make_bilingual!(NO_WARNING_MESSAGE, "現時並無特別報告。", "There is no special announcement.");
 
+make_bilingual!(
+	WARNING_USAGE_MESSAGE,
+	"用法：
+/warning - 獲取當前嘅天氣警告
+/warning diff - 比較重新發出嘅警告同上一次發出嘅內容",
+	"Usage:
+/warning - Get the current weather warnings
+/warning diff - Compare re-issued warnings with their previous issuance"
+);
+
+make_bilingual!(
+	NO_WARNING_DIFF_MESSAGE,
+	"現時生效嘅警告自發出以來未有更改。",
+	"The warnings in force have not changed since they were issued."
+);
+
 // Briefing titles
 
 make_bilingual!(BRIEFING_TITLE, "本港地區天氣預報", "Local Weather Forecast");
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::sync::Arc;
@@ -9,9 +9,15 @@ use teloxide::{
 	prelude::*,
 };
 
-use crate::{database::Connection, http, trigger};
+use self::misc::{Admins, RequireStart};
+use crate::{database::Connection, http, outbound, trigger};
 
-pub fn connect<S>(token: S, db_conn: Connection) -> Dispatcher<Bot, RequestError, DefaultKey>
+pub fn connect<S>(
+	token: S,
+	admins: Vec<u64>,
+	require_start: bool,
+	db_conn: Connection,
+) -> Dispatcher<Bot, RequestError, DefaultKey>
 where
 	S: Into<String> + Send + Sync,
 {
@@ -20,6 +26,8 @@ where
 	let bot = Bot::with_client(token, http::client());
 
 	trigger::set_bot(bot.clone());
+	trigger::set_db(db_conn.clone());
+	outbound::set_db(db_conn.clone());
 
 	{
 		let bot = bot.clone();
@@ -33,6 +41,8 @@ where
 
 	let mut dependencies = DependencyMap::new();
 	dependencies.insert(db_conn);
+	dependencies.insert(Admins(admins.into_iter().map(UserId).collect()));
+	dependencies.insert(RequireStart(require_start));
 
 	let error_handler = |e| async move {
 		log::error!("{e}");
@@ -50,14 +60,19 @@ fn schema() -> UpdateHandler<RequestError> {
 	use callback::schema as callback;
 	use command::schema as command;
 	use inlineq::schema as inlineq;
+	use member::schema as member;
+	use migrate::schema as migrate;
 
 	dptree::entry()
-		.branch(Update::filter_message().branch(command()))
+		.branch(Update::filter_message().branch(migrate()).branch(command()))
 		.branch(Update::filter_callback_query().branch(callback()))
 		.branch(Update::filter_inline_query().branch(inlineq()))
+		.branch(Update::filter_my_chat_member().branch(member()))
 }
 
 mod callback;
 mod command;
 mod inlineq;
+mod member;
+mod migrate;
 mod misc;
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 use teloxide::{RequestError, dispatching::UpdateHandler, prelude::*, utils::command::BotCommands};
@@ -10,6 +10,16 @@ pub fn schema() -> UpdateHandler<RequestError> {
 
 	dptree::filter_map(move |callback: CallbackQuery| callback.data.and_then(|s| Command::parse(&s, "").ok()))
 		.branch(case!(Command::SetLang(lang)).endpoint(setlang::setlang))
+		.branch(case!(Command::PinWarning(enable)).endpoint(pinwarning::pinwarning))
+		.branch(case!(Command::Settings).endpoint(settings::settings))
+		.branch(case!(Command::Silent(enable)).endpoint(silent::silent))
+		.branch(case!(Command::Snooze(args)).endpoint(snooze::snooze))
+		.branch(case!(Command::WarnFilter(code)).endpoint(warnfilter::warnfilter))
 }
 
+mod pinwarning;
 mod setlang;
+mod settings;
+mod silent;
+mod snooze;
+mod warnfilter;
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::str::FromStr;
@@ -11,8 +11,8 @@ use teloxide::{
 use crate::{
 	answer,
 	database::{Connection, types::lang::Lang},
-	statics,
-	telegram::misc::{setlang_ikb, setlang_internal, start_first},
+	outbound, statics,
+	telegram::misc::{RequireStart, find_chat, setlang_ikb, setlang_internal, start_first},
 };
 
 pub(super) async fn setlang(
@@ -20,6 +20,7 @@ pub(super) async fn setlang(
 	callback: CallbackQuery,
 	bot: Bot,
 	db_conn: Connection,
+	require_start: RequireStart,
 ) -> ResponseResult<()> {
 	if callback.message.is_none() {
 		return respond(());
@@ -29,9 +30,10 @@ pub(super) async fn setlang(
 	let chat_id = message.chat().id;
 
 	if lang.is_none() {
-		bot.edit_message_text(chat_id, message.id(), statics::SETLANG_QUESTION_BILINGUAL)
-			.reply_markup(InlineKeyboardMarkup { inline_keyboard: setlang_ikb() })
-			.await?;
+		let request = bot
+			.edit_message_text(chat_id, message.id(), statics::SETLANG_QUESTION_BILINGUAL)
+			.reply_markup(InlineKeyboardMarkup { inline_keyboard: setlang_ikb() });
+		outbound::send(chat_id, request).await?;
 
 		return respond(());
 	}
@@ -40,7 +42,7 @@ pub(super) async fn setlang(
 		return respond(());
 	};
 
-	let chat = match db_conn.select_chat(chat_id.0).await {
+	let chat = match find_chat(chat_id, Some(&callback.from), &db_conn, require_start).await {
 		Ok(chat) => {
 			let Some(chat) = chat else {
 				return start_first(bot, chat_id).await;
@@ -55,7 +57,8 @@ pub(super) async fn setlang(
 	};
 
 	if setlang_internal(&lang, chat, db_conn).await {
-		bot.edit_message_text(chat_id, message.id(), answer::setlang(&lang)).parse_mode(ParseMode::Html).await?;
+		let request = bot.edit_message_text(chat_id, message.id(), answer::setlang(&lang)).parse_mode(ParseMode::Html);
+		outbound::send(chat_id, request).await?;
 	}
 
 	respond(())
@@ -0,0 +1,43 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use crate::{
+	database::Connection,
+	outbound,
+	telegram::misc::{RequireStart, find_chat, settings_message, start_first},
+};
+
+pub(super) async fn settings(
+	callback: CallbackQuery,
+	bot: Bot,
+	db_conn: Connection,
+	require_start: RequireStart,
+) -> ResponseResult<()> {
+	let Some(message) = callback.message else {
+		return respond(());
+	};
+
+	let chat_id = message.chat().id;
+
+	let chat = match find_chat(chat_id, Some(&callback.from), &db_conn, require_start).await {
+		Ok(chat) => {
+			let Some(chat) = chat else {
+				return start_first(bot, chat_id).await;
+			};
+
+			chat
+		}
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	let (text, ikb) = settings_message(&chat);
+	let request = bot.edit_message_text(chat_id, message.id(), text).parse_mode(ParseMode::Html).reply_markup(ikb);
+	outbound::send(chat_id, request).await?;
+
+	respond(())
+}
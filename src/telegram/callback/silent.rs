@@ -0,0 +1,50 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use crate::{
+	database::Connection,
+	outbound,
+	telegram::misc::{RequireStart, find_chat, settings_message, silent_internal, start_first},
+};
+
+pub(super) async fn silent(
+	enable: Option<bool>,
+	callback: CallbackQuery,
+	bot: Bot,
+	db_conn: Connection,
+	require_start: RequireStart,
+) -> ResponseResult<()> {
+	let Some(message) = callback.message else {
+		return respond(());
+	};
+
+	let chat_id = message.chat().id;
+
+	let mut chat = match find_chat(chat_id, Some(&callback.from), &db_conn, require_start).await {
+		Ok(chat) => {
+			let Some(chat) = chat else {
+				return start_first(bot, chat_id).await;
+			};
+
+			chat
+		}
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	let enable = enable.unwrap_or(!chat.silent_minor);
+
+	if silent_internal(enable, &chat, db_conn).await {
+		chat.silent_minor = enable;
+
+		let (text, ikb) = settings_message(&chat);
+		let request = bot.edit_message_text(chat_id, message.id(), text).parse_mode(ParseMode::Html).reply_markup(ikb);
+		outbound::send(chat_id, request).await?;
+	}
+
+	respond(())
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use crate::{
+	answer,
+	database::Connection,
+	outbound,
+	telegram::misc::{RequireStart, find_chat, snooze_internal, start_first},
+};
+
+pub(super) async fn snooze(
+	args: Option<String>,
+	callback: CallbackQuery,
+	bot: Bot,
+	db_conn: Connection,
+	require_start: RequireStart,
+) -> ResponseResult<()> {
+	let (Some(message), Some(args)) = (callback.message, args) else {
+		return respond(());
+	};
+
+	let chat_id = message.chat().id;
+
+	let chat = match find_chat(chat_id, Some(&callback.from), &db_conn, require_start).await {
+		Ok(chat) => {
+			let Some(chat) = chat else {
+				return start_first(bot, chat_id).await;
+			};
+
+			chat
+		}
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	if let Some(until) = snooze_internal(&args, &chat, db_conn).await {
+		let request =
+			bot.edit_message_text(chat_id, message.id(), answer::snooze(until, &chat.lang)).parse_mode(ParseMode::Html);
+		outbound::send(chat_id, request).await?;
+	}
+
+	respond(())
+}
@@ -1,6 +1,8 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
+use std::str::FromStr;
+
 use teloxide::{
 	RequestError,
 	dispatching::UpdateHandler,
@@ -9,26 +11,80 @@ use teloxide::{
 	utils::command::{BotCommands, ParseError},
 };
 
-use super::misc::start_first;
-use crate::database::Connection;
+use super::misc::{RequireStart, find_chat, start_first};
+use crate::database::{Connection, types::lang::Lang};
 use macros::command_endpoint;
 
 #[derive(BotCommands, Clone)]
 #[command(rename_rule = "lowercase")]
 pub enum Command {
+	#[command(parse_with = parse_addchannel)]
+	AddChannel(Option<i64>, Option<Lang>),
 	Briefing,
 	Bulletin,
+	Channels,
+
+	#[command(parse_with = parse_delchannel)]
+	DelChannel(Option<i64>),
+
+	#[command(parse_with = parse_switch)]
+	Earthquake(Option<bool>),
 
 	#[command(parse_with = parse_forecast)]
 	Forecast(Option<usize>),
 	Help,
+
+	#[command(parse_with = parse_optional)]
+	Keyword(Option<String>),
+
+	#[command(parse_with = parse_optional)]
+	Lunar(Option<String>),
+	Moon,
+
+	#[command(hide, parse_with = parse_switch)]
+	PinWarning(Option<bool>),
 	Purge,
 
-	#[command(parse_with = parse_setlang)]
+	#[command(parse_with = parse_optional)]
+	Quiet(Option<String>),
+
+	#[command(parse_with = parse_optional)]
+	Rain(Option<String>),
+
+	#[command(parse_with = parse_switch)]
+	RainAlert(Option<bool>),
+
+	#[command(parse_with = parse_optional)]
+	Schedule(Option<String>),
+
+	#[command(parse_with = parse_optional)]
 	SetLang(Option<String>),
 	Settings,
+
+	#[command(hide, parse_with = parse_switch)]
+	Silent(Option<bool>),
+
+	#[command(parse_with = parse_optional)]
+	Snooze(Option<String>),
 	Start,
-	Warning,
+	Subscribe,
+	Sun,
+
+	#[command(parse_with = parse_optional)]
+	Temp(Option<String>),
+
+	#[command(parse_with = parse_optional)]
+	Threshold(Option<String>),
+
+	#[command(parse_with = parse_optional)]
+	Tide(Option<String>),
+	Unsubscribe,
+
+	#[command(hide, parse_with = parse_optional)]
+	WarnFilter(Option<String>),
+
+	#[command(parse_with = parse_optional)]
+	Warning(Option<String>),
 }
 
 #[allow(clippy::needless_pass_by_value, clippy::unnecessary_wraps)]
@@ -44,12 +100,36 @@ fn parse_forecast(input: String) -> Result<(Option<usize>,), ParseError> {
 	}
 }
 
+#[allow(clippy::needless_pass_by_value, clippy::unnecessary_wraps)]
+fn parse_switch(input: String) -> Result<(Option<bool>,), ParseError> {
+	let input = match input.trim().to_ascii_lowercase().as_str() {
+		"on" => Some(true),
+		"off" => Some(false),
+		_ => None,
+	};
+
+	Ok((input,))
+}
+
 #[allow(clippy::unnecessary_wraps)]
-fn parse_setlang(input: String) -> Result<(Option<String>,), ParseError> {
+fn parse_optional(input: String) -> Result<(Option<String>,), ParseError> {
 	let input = (!input.is_empty()).then_some(input);
 	Ok((input,))
 }
 
+#[allow(clippy::needless_pass_by_value, clippy::unnecessary_wraps)]
+fn parse_addchannel(input: String) -> Result<(Option<i64>, Option<Lang>), ParseError> {
+	let mut input = input.split_whitespace();
+	let id = input.next().and_then(|s| s.parse().ok());
+	let lang = input.next().and_then(|s| Lang::from_str(s).ok());
+	Ok((id, lang))
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::unnecessary_wraps)]
+fn parse_delchannel(input: String) -> Result<(Option<i64>,), ParseError> {
+	Ok((input.trim().parse().ok(),))
+}
+
 pub fn schema() -> UpdateHandler<RequestError> {
 	dptree::entry().branch(
 		dptree::filter_map(move |message: Message, me: Me| {
@@ -58,18 +138,45 @@ pub fn schema() -> UpdateHandler<RequestError> {
 		})
 		.branch(command_endpoint!(Command::Start))
 		.branch(
-			dptree::filter_map_async(|message: Message, db_conn: Connection| async move {
+			dptree::filter_map_async(|message: Message, db_conn: Connection, require_start: RequireStart| async move {
 				let chat_id = message.chat.id;
-				db_conn.select_chat(chat_id.0).await.ok().flatten()
+				match find_chat(chat_id, message.from.as_ref(), &db_conn, require_start).await {
+					Ok(chat) => chat,
+					Err(e) => {
+						log::error!("{e}");
+						None
+					}
+				}
 			})
 			.branch(command_endpoint!(Command::Help))
 			.branch(command_endpoint!(Command::Settings))
+			.branch(command_endpoint!(Command::PinWarning(enable)))
 			.branch(command_endpoint!(Command::Purge))
 			.branch(command_endpoint!(Command::SetLang(lang)))
 			.branch(command_endpoint!(Command::Briefing))
 			.branch(command_endpoint!(Command::Bulletin))
 			.branch(command_endpoint!(Command::Forecast(days)))
-			.branch(command_endpoint!(Command::Warning)),
+			.branch(command_endpoint!(Command::Warning(args)))
+			.branch(command_endpoint!(Command::Earthquake(enable)))
+			.branch(command_endpoint!(Command::Temp(place)))
+			.branch(command_endpoint!(Command::Rain(place)))
+			.branch(command_endpoint!(Command::Tide(station)))
+			.branch(command_endpoint!(Command::Sun))
+			.branch(command_endpoint!(Command::Moon))
+			.branch(command_endpoint!(Command::Lunar(args)))
+			.branch(command_endpoint!(Command::Silent(enable)))
+			.branch(command_endpoint!(Command::Snooze(args)))
+			.branch(command_endpoint!(Command::Subscribe))
+			.branch(command_endpoint!(Command::Keyword(args)))
+			.branch(command_endpoint!(Command::Quiet(args)))
+			.branch(command_endpoint!(Command::RainAlert(enable)))
+			.branch(command_endpoint!(Command::Schedule(args)))
+			.branch(command_endpoint!(Command::Threshold(args)))
+			.branch(command_endpoint!(Command::WarnFilter(code)))
+			.branch(command_endpoint!(Command::Unsubscribe))
+			.branch(command_endpoint!(Command::AddChannel(id, lang)))
+			.branch(command_endpoint!(Command::DelChannel(id)))
+			.branch(command_endpoint!(Command::Channels)),
 		)
 		.branch(dptree::endpoint(|message: Message, bot: Bot| async move {
 			let chat_id = message.chat.id;
@@ -78,14 +185,35 @@ pub fn schema() -> UpdateHandler<RequestError> {
 	)
 }
 
+mod addchannel;
 mod briefing;
 mod bulletin;
+mod channels;
+mod delchannel;
+mod earthquake;
 mod forecast;
 mod help;
+mod keyword;
+mod lunar;
+mod moon;
+mod pinwarning;
 mod purge;
+mod quiet;
+mod rain;
+mod rainalert;
+mod schedule;
 mod setlang;
 mod settings;
+mod silent;
+mod snooze;
 mod start;
+mod subscribe;
+mod sun;
+mod temp;
+mod threshold;
+mod tide;
+mod unsubscribe;
+mod warnfilter;
 mod warning;
 
 mod macros;
@@ -0,0 +1,46 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{
+		Connection,
+		entities::{channel::Channel, chat::Chat},
+		types::lang::Lang,
+	},
+	statics::get_bilingual_str,
+	telegram::misc::Admins,
+};
+
+pub(super) async fn addchannel(
+	(id, lang): (Option<i64>, Option<Lang>),
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	admins: Admins,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	if !admins.contains(&message) {
+		return respond(());
+	}
+
+	let Some(id) = id else {
+		reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, CHANNEL_USAGE_MESSAGE), bot)?;
+		return respond(());
+	};
+
+	let channel = Channel { id, lang: lang.unwrap_or(Lang::Bilingual) };
+
+	if let Err(e) = db_conn.insert_channel(&channel).await {
+		log::error!("{e}");
+		return respond(());
+	}
+
+	reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, CHANNEL_ADDED_MESSAGE), bot)?;
+
+	respond(())
+}
@@ -1,23 +1,36 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use teloxide::{prelude::*, types::ParseMode};
 
 use super::macros::reply_html;
 use crate::{
-	answer::{Answer as _, Briefing as Answer},
-	database::entities::chat::Chat,
+	answer::{self, Answer as _, Briefing as Answer},
+	database::{Connection, entities::chat::Chat},
 	tool::ext::NonEmptyExt as _,
 };
 
-pub(super) async fn briefing(message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+pub(super) async fn briefing(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
 	let chat_id = message.chat.id;
 
-	if let Some(text) = Answer::answer(&chat.lang).await.get_non_empty() {
-		reply_html!(chat_id, message.id, text, bot)?;
-	} else {
+	let Some(mut text) = Answer::answer(&chat.lang).await.get_non_empty() else {
 		log::error!("Empty briefing data");
+		return respond(());
+	};
+
+	let lunar = match db_conn.select_lunar_briefing(chat.id).await {
+		Ok(lunar) => lunar,
+		Err(e) => {
+			log::error!("{e}");
+			false
+		}
+	};
+
+	if lunar && let Some(line) = answer::lunar_briefing_line(&chat.lang).await {
+		text = format!("{text}\n\n{line}");
 	}
 
+	reply_html!(chat_id, message.id, text, bot)?;
+
 	respond(())
 }
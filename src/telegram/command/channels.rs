@@ -0,0 +1,49 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+	telegram::misc::Admins,
+};
+
+pub(super) async fn channels(
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	admins: Admins,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	if !admins.contains(&message) {
+		return respond(());
+	}
+
+	let channels = match db_conn.select_channels().await {
+		Ok(channels) => channels,
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	if channels.is_empty() {
+		reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, CHANNEL_LIST_EMPTY_MESSAGE), bot)?;
+		return respond(());
+	}
+
+	let mut text = get_bilingual_str!(chat.lang, CHANNEL_LIST_TITLE).to_string();
+	for channel in channels {
+		write!(text, "\n<code>{}</code> {}", channel.id, channel.lang).ok();
+	}
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+	telegram::misc::Admins,
+};
+
+pub(super) async fn delchannel(
+	id: Option<i64>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	admins: Admins,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	if !admins.contains(&message) {
+		return respond(());
+	}
+
+	let Some(id) = id else {
+		reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, CHANNEL_USAGE_MESSAGE), bot)?;
+		return respond(());
+	};
+
+	let text = match db_conn.delete_channel(id).await {
+		Ok(res) if res.rows_affected() > 0 => get_bilingual_str!(chat.lang, CHANNEL_REMOVED_MESSAGE),
+		Ok(_) => get_bilingual_str!(chat.lang, CHANNEL_NOT_FOUND_MESSAGE),
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
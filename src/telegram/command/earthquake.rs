@@ -0,0 +1,63 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{
+	prelude::*,
+	types::{ParseMode, ReplyParameters},
+};
+
+use super::macros::reply_html;
+use crate::{
+	answer::{Answer as _, Earthquake as Answer},
+	database::{Connection, entities::chat::Chat},
+	outbound,
+	statics::get_bilingual_str,
+};
+
+pub(super) async fn earthquake(
+	enable: Option<bool>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(enable) = enable else {
+		let reports = Answer::answer(&chat.lang).await;
+
+		if reports.is_empty() {
+			let request = bot
+				.send_message(chat_id, get_bilingual_str!(chat.lang, NO_EARTHQUAKE_MESSAGE))
+				.reply_parameters(ReplyParameters::new(message.id));
+			outbound::send(chat_id, request).await?;
+		}
+
+		for r in reports {
+			reply_html!(chat_id, message.id, r, bot)?;
+		}
+
+		return respond(());
+	};
+
+	let res = if enable {
+		db_conn.insert_earthquake_alert(chat.id).await
+	} else {
+		db_conn.delete_earthquake_alert(chat.id).await
+	};
+
+	if let Err(e) = res {
+		log::error!("{e}");
+		return respond(());
+	}
+
+	let text = if enable {
+		get_bilingual_str!(chat.lang, EARTHQUAKE_ALERT_ON_MESSAGE)
+	} else {
+		get_bilingual_str!(chat.lang, EARTHQUAKE_ALERT_OFF_MESSAGE)
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -1,16 +1,17 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use teloxide::{prelude::*, types::ReplyParameters};
 
-use crate::{database::entities::chat::Chat, statics::get_bilingual_str};
+use crate::{database::entities::chat::Chat, outbound, statics::get_bilingual_str};
 
 pub(super) async fn help(message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
 	let chat_id = message.chat.id;
 
-	bot.send_message(chat_id, get_bilingual_str!(chat.lang, HELP_MESSAGE))
-		.reply_parameters(ReplyParameters::new(message.id))
-		.await?;
+	let request = bot
+		.send_message(chat_id, get_bilingual_str!(chat.lang, HELP_MESSAGE))
+		.reply_parameters(ReplyParameters::new(message.id));
+	outbound::send(chat_id, request).await?;
 
 	respond(())
 }
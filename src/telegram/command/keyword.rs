@@ -0,0 +1,89 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+};
+
+pub(super) async fn keyword(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(args) = args else {
+		return list(message, bot, chat, db_conn).await;
+	};
+
+	let usage = get_bilingual_str!(chat.lang, KEYWORD_USAGE_MESSAGE);
+
+	let (action, keyword) = args.trim().split_once(char::is_whitespace).unwrap_or((args.trim(), ""));
+	let keyword = keyword.trim();
+
+	let text = match (action.to_ascii_lowercase().as_str(), keyword.is_empty()) {
+		("add", false) => match db_conn.insert_keyword(chat.id, keyword).await {
+			Ok(_) => get_bilingual_str!(chat.lang, KEYWORD_ADDED_MESSAGE),
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+		("remove" | "delete", false) => match db_conn.delete_keyword(chat.id, keyword).await {
+			Ok(res) if res.rows_affected() > 0 => get_bilingual_str!(chat.lang, KEYWORD_REMOVED_MESSAGE),
+			Ok(_) => get_bilingual_str!(chat.lang, KEYWORD_NOT_FOUND_MESSAGE),
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+		("clear", true) => match db_conn.delete_keywords(chat.id).await {
+			Ok(_) => get_bilingual_str!(chat.lang, KEYWORD_CLEARED_MESSAGE),
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+		_ => usage,
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
+
+async fn list(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let keywords = match db_conn.select_keywords(chat.id).await {
+		Ok(keywords) => keywords,
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	let mut text = if keywords.is_empty() {
+		get_bilingual_str!(chat.lang, KEYWORD_LIST_EMPTY_MESSAGE).to_string()
+	} else {
+		get_bilingual_str!(chat.lang, KEYWORD_LIST_TITLE).to_string()
+	};
+
+	for keyword in keywords {
+		write!(text, "\n{}", html::escape(&keyword)).ok();
+	}
+
+	write!(text, "\n\n{}", get_bilingual_str!(chat.lang, KEYWORD_USAGE_MESSAGE)).ok();
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
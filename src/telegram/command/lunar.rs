@@ -0,0 +1,73 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::NaiveDate;
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+	tool::time::hong_kong_now,
+	weather::lunar,
+};
+
+pub(super) async fn lunar(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let date = match args.as_deref().map(str::trim) {
+		None => hong_kong_now().date_naive(),
+		Some(arg) if arg.eq_ignore_ascii_case("on") || arg.eq_ignore_ascii_case("off") => {
+			return briefing(arg.eq_ignore_ascii_case("on"), message, bot, chat, db_conn).await;
+		}
+		Some(arg) => {
+			let Some(date) = NaiveDate::parse_from_str(arg, "%Y-%m-%d").ok().filter(|date| lunar::is_supported(*date))
+			else {
+				reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, LUNAR_USAGE_MESSAGE), bot)?;
+				return respond(());
+			};
+			date
+		}
+	};
+
+	let text = match lunar::get(date).await {
+		Some(lunar) => answer::lunar(date, &lunar, &chat.lang),
+		None => get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT).to_string(),
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
+
+async fn briefing(enable: bool, message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let res = if enable {
+		db_conn.insert_lunar_briefing(chat.id).await
+	} else {
+		db_conn.delete_lunar_briefing(chat.id).await
+	};
+
+	if let Err(e) = res {
+		log::error!("{e}");
+		return respond(());
+	}
+
+	let text = if enable {
+		get_bilingual_str!(chat.lang, LUNAR_BRIEFING_ON_MESSAGE)
+	} else {
+		get_bilingual_str!(chat.lang, LUNAR_BRIEFING_OFF_MESSAGE)
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 macro_rules! command_endpoint {
@@ -37,10 +37,13 @@ macro_rules! command_endpoint {
 
 macro_rules! reply_html {
 	($to:expr, $on:expr, $text:expr, $bot:expr) => {
-		$bot.send_message($to, $text)
-			.parse_mode(ParseMode::Html)
-			.reply_parameters(::teloxide::types::ReplyParameters::new($on))
-			.await
+		$crate::outbound::send(
+			$to,
+			$bot.send_message($to, $text)
+				.parse_mode(ParseMode::Html)
+				.reply_parameters(::teloxide::types::ReplyParameters::new($on)),
+		)
+		.await
 	};
 }
 
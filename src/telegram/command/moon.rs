@@ -0,0 +1,31 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::Utc;
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::entities::chat::Chat,
+	statics::get_bilingual_str,
+	tool::time::HONG_KONG,
+	weather::astronomy::{self, Body},
+};
+
+pub(super) async fn moon(message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let now = Utc::now();
+	let today = now.with_timezone(&HONG_KONG).date_naive();
+	let phase = astronomy::moon_phase(now);
+
+	let text = match astronomy::get(Body::Moon, today).await {
+		Some(times) => answer::astronomy(Body::Moon, today, times.as_ref(), Some(phase), &chat.lang),
+		None => get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT).to_string(),
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -1,9 +1,12 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use teloxide::prelude::*;
 
-use crate::database::{Connection, entities::chat::Chat};
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	outbound,
+};
 
 pub(super) async fn purge(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
 	let chat_id = message.chat.id;
@@ -13,7 +16,7 @@ pub(super) async fn purge(message: Message, bot: Bot, chat: Chat, db_conn: Conne
 		return respond(());
 	}
 
-	bot.send_message(chat_id, "\u{1F44B}").await?;
+	outbound::send(chat_id, bot.send_message(chat_id, "\u{1F44B}")).await?;
 
 	respond(())
 }
@@ -0,0 +1,68 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::NaiveTime;
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+	telegram::misc::quiet_hours_string,
+};
+
+/// Parses quiet hours like `23:00-07:00`.
+fn parse_window(s: &str) -> Option<(NaiveTime, NaiveTime)> {
+	let (start, end) = s.split_once('-')?;
+	let start = NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?;
+	let end = NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?;
+
+	(start != end).then_some((start, end))
+}
+
+pub(super) async fn quiet(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let usage = get_bilingual_str!(chat.lang, QUIET_USAGE_MESSAGE);
+
+	let window = match args.as_deref().map(str::trim) {
+		None => {
+			let text = format!("{}\n\n{usage}", quiet_hours_string(&chat));
+			reply_html!(chat_id, message.id, text, bot)?;
+
+			return respond(());
+		}
+		Some(s) if s.eq_ignore_ascii_case("off") => None,
+		Some(s) => {
+			let Some(window) = parse_window(s) else {
+				reply_html!(chat_id, message.id, usage, bot)?;
+
+				return respond(());
+			};
+
+			Some(window)
+		}
+	};
+
+	let (start, end) = window.unzip();
+	if let Err(e) = db_conn.update_chat_quiet_hours(chat.id, start, end).await {
+		log::error!("{e}");
+		return respond(());
+	}
+
+	let text = if window.is_some() {
+		get_bilingual_str!(chat.lang, QUIET_ON_MESSAGE)
+	} else {
+		get_bilingual_str!(chat.lang, QUIET_OFF_MESSAGE)
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
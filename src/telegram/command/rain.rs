@@ -0,0 +1,40 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::slice;
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::entities::chat::Chat,
+	statics::get_bilingual_str,
+	tool::{data::out_dated, fuzzy},
+	weather::{Bulletin, WeatherData as _},
+};
+
+pub(super) async fn rain(place: Option<String>, message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(data) = Bulletin::get().await.filter(|data| !out_dated(data.update_time.to_utc())) else {
+		reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT), bot)?;
+		return respond(());
+	};
+
+	let readings = match place.as_deref() {
+		None => data.rainfall.as_slice(),
+		Some(place) => {
+			let candidates = data.rainfall.iter().map(|r| (r, [r.place.zh.as_str(), r.place.en.as_str()]));
+			let Some(reading) = fuzzy::best_match(place, candidates) else {
+				reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, RAIN_DISTRICT_NOT_FOUND_MESSAGE), bot)?;
+				return respond(());
+			};
+			slice::from_ref(reading)
+		}
+	};
+
+	reply_html!(chat_id, message.id, answer::rainfall(&data, readings, &chat.lang), bot)?;
+
+	respond(())
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+};
+
+pub(super) async fn rainalert(
+	enable: Option<bool>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let enable = match enable {
+		Some(enable) => enable,
+		None => match db_conn.select_rain_alert(chat.id).await {
+			Ok(enabled) => !enabled,
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+	};
+
+	let res = if enable { db_conn.insert_rain_alert(chat.id).await } else { db_conn.delete_rain_alert(chat.id).await };
+
+	if let Err(e) = res {
+		log::error!("{e}");
+		return respond(());
+	}
+
+	let text = if enable {
+		get_bilingual_str!(chat.lang, RAIN_ALERT_ON_MESSAGE)
+	} else {
+		get_bilingual_str!(chat.lang, RAIN_ALERT_OFF_MESSAGE)
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
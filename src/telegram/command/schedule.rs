@@ -0,0 +1,109 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{fmt::Write, str::FromStr};
+
+use chrono::NaiveTime;
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{
+		Connection,
+		entities::{chat::Chat, schedule::Schedule},
+		types::product::Product,
+	},
+	statics::get_bilingual_str,
+};
+
+fn parse_time(s: &str) -> Option<NaiveTime> {
+	NaiveTime::parse_from_str(s, "%H:%M").ok()
+}
+
+pub(super) async fn schedule(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(args) = args else {
+		return list(message, bot, chat, db_conn).await;
+	};
+
+	let usage = get_bilingual_str!(chat.lang, SCHEDULE_USAGE_MESSAGE);
+
+	let mut args = args.split_whitespace();
+	let text = match args.next().map(str::to_ascii_lowercase).as_deref() {
+		Some("clear" | "off") => match db_conn.delete_schedules(chat.id).await {
+			Ok(_) => get_bilingual_str!(chat.lang, SCHEDULE_CLEARED_MESSAGE),
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+		Some("remove" | "delete") => match args.next().and_then(parse_time) {
+			Some(time) => match db_conn.delete_schedule(chat.id, time).await {
+				Ok(res) if res.rows_affected() > 0 => get_bilingual_str!(chat.lang, SCHEDULE_REMOVED_MESSAGE),
+				Ok(_) => get_bilingual_str!(chat.lang, SCHEDULE_NOT_FOUND_MESSAGE),
+				Err(e) => {
+					log::error!("{e}");
+					return respond(());
+				}
+			},
+			None => usage,
+		},
+		Some(time) => {
+			let time = parse_time(time);
+			let product = args.next().map_or(Ok(Product::Briefing), Product::from_str);
+
+			match (time, product) {
+				(Some(time), Ok(product)) => {
+					let schedule = Schedule { chat_id: chat.id, time, product };
+					if let Err(e) = db_conn.insert_schedule(&schedule).await {
+						log::error!("{e}");
+						return respond(());
+					}
+
+					get_bilingual_str!(chat.lang, SCHEDULE_ADDED_MESSAGE)
+				}
+				_ => usage,
+			}
+		}
+		None => usage,
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
+
+async fn list(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let schedules = match db_conn.select_schedules(chat.id).await {
+		Ok(schedules) => schedules,
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	let mut text = if schedules.is_empty() {
+		get_bilingual_str!(chat.lang, SCHEDULE_LIST_EMPTY_MESSAGE).to_string()
+	} else {
+		get_bilingual_str!(chat.lang, SCHEDULE_LIST_TITLE).to_string()
+	};
+
+	for schedule in schedules {
+		write!(text, "\n{} {}", schedule.time.format("%H:%M"), schedule.product.title(&chat.lang)).ok();
+	}
+
+	write!(text, "\n\n{}", get_bilingual_str!(chat.lang, SCHEDULE_USAGE_MESSAGE)).ok();
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
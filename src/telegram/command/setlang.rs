@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::str::FromStr;
@@ -12,7 +12,7 @@ use super::macros::reply_html;
 use crate::{
 	answer,
 	database::{Connection, entities::chat::Chat, types::lang::Lang},
-	statics,
+	outbound, statics,
 	telegram::misc::{setlang_ikb, setlang_internal},
 };
 
@@ -37,10 +37,11 @@ pub(super) async fn setlang(
 }
 
 async fn setlang_question(message: Message, bot: Bot) -> ResponseResult<()> {
-	bot.send_message(message.chat.id, statics::SETLANG_QUESTION_BILINGUAL)
+	let request = bot
+		.send_message(message.chat.id, statics::SETLANG_QUESTION_BILINGUAL)
 		.reply_markup(ReplyMarkup::inline_kb(setlang_ikb()))
-		.reply_parameters(ReplyParameters::new(message.id))
-		.await?;
+		.reply_parameters(ReplyParameters::new(message.id));
+	outbound::send(message.chat.id, request).await?;
 
 	respond(())
 }
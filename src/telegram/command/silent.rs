@@ -0,0 +1,35 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+	telegram::misc::silent_internal,
+};
+
+pub(super) async fn silent(
+	enable: Option<bool>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let enable = enable.unwrap_or(!chat.silent_minor);
+
+	if silent_internal(enable, &chat, db_conn).await {
+		let text = if enable {
+			get_bilingual_str!(chat.lang, SILENT_ON_MESSAGE)
+		} else {
+			get_bilingual_str!(chat.lang, SILENT_OFF_MESSAGE)
+		};
+
+		reply_html!(chat_id, message.id, text, bot)?;
+	}
+
+	respond(())
+}
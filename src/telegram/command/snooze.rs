@@ -0,0 +1,51 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::Utc;
+use teloxide::{
+	prelude::*,
+	types::{ParseMode, ReplyParameters},
+};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::{Connection, entities::chat::Chat},
+	outbound,
+	statics::get_bilingual_str,
+	telegram::misc::{snooze_ikb, snooze_internal},
+};
+
+pub(super) async fn snooze(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(args) = args else {
+		let until = chat.snooze_until.filter(|_| chat.is_snoozed(Utc::now()));
+		let text =
+			format!("{}\n\n{}", answer::snooze(until, &chat.lang), get_bilingual_str!(chat.lang, SNOOZE_USAGE_MESSAGE));
+
+		let request = bot
+			.send_message(chat_id, text)
+			.parse_mode(ParseMode::Html)
+			.reply_parameters(ReplyParameters::new(message.id))
+			.reply_markup(snooze_ikb(&chat.lang));
+		outbound::send(chat_id, request).await?;
+
+		return respond(());
+	};
+
+	let text = match snooze_internal(&args, &chat, db_conn).await {
+		Some(until) => answer::snooze(until, &chat.lang),
+		None => get_bilingual_str!(chat.lang, SNOOZE_USAGE_MESSAGE).to_string(),
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
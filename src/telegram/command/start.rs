@@ -1,12 +1,13 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use teloxide::{prelude::*, types::ParseMode};
 
 use super::macros::reply_html;
 use crate::{
-	database::{Connection, entities::chat::Chat, types::lang::Lang},
+	database::{Connection, entities::chat::Chat},
 	statics::{self, get_bilingual_str},
+	telegram::misc::infer_lang,
 };
 
 pub(super) async fn start(message: Message, bot: Bot, db_conn: Connection) -> ResponseResult<()> {
@@ -25,13 +26,9 @@ pub(super) async fn start(message: Message, bot: Bot, db_conn: Connection) -> Re
 		return respond(());
 	}
 
-	let lang = message
-		.from
-		.and_then(|f| f.language_code.filter(|s| s.starts_with("zh")))
-		.and(Some(Lang::Chinese))
-		.unwrap_or(Lang::English);
+	let lang = infer_lang(message.from.as_ref());
 
-	let chat = Chat { id: chat_id.0, lang: lang.clone() };
+	let chat = Chat::new(chat_id.0, lang.clone());
 
 	if let Err(e) = db_conn.insert_chat(&chat).await {
 		log::error!("{e}");
@@ -0,0 +1,28 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::entities::chat::Chat,
+	statics::get_bilingual_str,
+	tool::time::hong_kong_now,
+	weather::astronomy::{self, Body},
+};
+
+pub(super) async fn sun(message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let today = hong_kong_now().date_naive();
+
+	let text = match astronomy::get(Body::Sun, today).await {
+		Some(times) => answer::astronomy(Body::Sun, today, times.as_ref(), None, &chat.lang),
+		None => get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT).to_string(),
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
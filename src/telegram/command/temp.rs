@@ -0,0 +1,40 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::slice;
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::entities::chat::Chat,
+	statics::get_bilingual_str,
+	tool::{data::out_dated, fuzzy},
+	weather::{Bulletin, WeatherData as _},
+};
+
+pub(super) async fn temp(place: Option<String>, message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(data) = Bulletin::get().await.filter(|data| !out_dated(data.update_time.to_utc())) else {
+		reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT), bot)?;
+		return respond(());
+	};
+
+	let readings = match place.as_deref() {
+		None => data.temperatures.as_slice(),
+		Some(place) => {
+			let candidates = data.temperatures.iter().map(|t| (t, [t.place.zh.as_str(), t.place.en.as_str()]));
+			let Some(reading) = fuzzy::best_match(place, candidates) else {
+				reply_html!(chat_id, message.id, get_bilingual_str!(chat.lang, TEMP_STATION_NOT_FOUND_MESSAGE), bot)?;
+				return respond(());
+			};
+			slice::from_ref(reading)
+		}
+	};
+
+	reply_html!(chat_id, message.id, answer::temperatures(&data, readings, &chat.lang), bot)?;
+
+	respond(())
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::{
+		Connection,
+		entities::{chat::Chat, threshold::Threshold},
+		types::metric::Metric,
+	},
+	statics::get_bilingual_str,
+};
+
+/// Parses rules like `temperature >= 33` or `uv>=8`.
+fn parse_rule(s: &str) -> Option<(Metric, bool, f32)> {
+	let s = s.replace(char::is_whitespace, "");
+
+	for (op, above) in [(">=", true), ("≥", true), ("<=", false), ("≤", false)] {
+		if let Some((metric, value)) = s.split_once(op) {
+			let value = value.parse::<f32>().ok().filter(|v| v.is_finite())?;
+			return Some((metric.parse().ok()?, above, value));
+		}
+	}
+
+	None
+}
+
+pub(super) async fn threshold(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(args) = args else {
+		return list(message, bot, chat, db_conn).await;
+	};
+
+	let usage = get_bilingual_str!(chat.lang, THRESHOLD_USAGE_MESSAGE);
+
+	let mut words = args.split_whitespace();
+	let text = match words.next().map(str::to_ascii_lowercase).as_deref() {
+		Some("clear" | "off") => match db_conn.delete_thresholds(chat.id).await {
+			Ok(_) => get_bilingual_str!(chat.lang, THRESHOLD_CLEARED_MESSAGE),
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		},
+		Some("remove" | "delete") => match words.next().and_then(|s| s.parse::<Metric>().ok()) {
+			Some(metric) => match db_conn.delete_threshold(chat.id, metric).await {
+				Ok(res) if res.rows_affected() > 0 => get_bilingual_str!(chat.lang, THRESHOLD_REMOVED_MESSAGE),
+				Ok(_) => get_bilingual_str!(chat.lang, THRESHOLD_NOT_FOUND_MESSAGE),
+				Err(e) => {
+					log::error!("{e}");
+					return respond(());
+				}
+			},
+			None => usage,
+		},
+		Some(_) => match parse_rule(&args) {
+			Some((metric, above, value)) => {
+				let threshold = Threshold { chat_id: chat.id, metric, above, value, triggered: false };
+				if let Err(e) = db_conn.insert_threshold(&threshold).await {
+					log::error!("{e}");
+					return respond(());
+				}
+
+				get_bilingual_str!(chat.lang, THRESHOLD_ADDED_MESSAGE)
+			}
+			None => usage,
+		},
+		None => usage,
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
+
+async fn list(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let thresholds = match db_conn.select_thresholds(chat.id).await {
+		Ok(thresholds) => thresholds,
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	let mut text = if thresholds.is_empty() {
+		get_bilingual_str!(chat.lang, THRESHOLD_LIST_EMPTY_MESSAGE).to_string()
+	} else {
+		get_bilingual_str!(chat.lang, THRESHOLD_LIST_TITLE).to_string()
+	};
+
+	for threshold in thresholds {
+		write!(text, "\n{}", answer::threshold_rule(&threshold, &chat.lang)).ok();
+	}
+
+	write!(text, "\n\n{}", get_bilingual_str!(chat.lang, THRESHOLD_USAGE_MESSAGE)).ok();
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	answer,
+	database::entities::chat::Chat,
+	statics::get_bilingual_str,
+	tool::time::hong_kong_now,
+	weather::tide::{self, DEFAULT_STATION},
+};
+
+pub(super) async fn tide(station: Option<String>, message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let station = match station.as_deref().map(tide::find_station) {
+		None => DEFAULT_STATION,
+		Some(Some(station)) => station,
+		Some(None) => {
+			let text = format!(
+				"{}\n{}",
+				get_bilingual_str!(chat.lang, TIDE_STATION_NOT_FOUND_MESSAGE),
+				answer::tide_stations(&chat.lang)
+			);
+			reply_html!(chat_id, message.id, text, bot)?;
+			return respond(());
+		}
+	};
+
+	let today = hong_kong_now().date_naive();
+	let tomorrow = today.succ_opt().unwrap_or(today);
+
+	let text = match tide::get(station, today, tomorrow).await {
+		Some(tides) => answer::tide(station, &tides, &chat.lang),
+		None => get_bilingual_str!(chat.lang, SERVER_ERROR_TIMEOUT).to_string(),
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use super::macros::reply_html;
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	statics::get_bilingual_str,
+};
+
+pub(super) async fn unsubscribe(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let text = match db_conn.delete_subscription(chat.id).await {
+		Ok(res) if res.rows_affected() > 0 => get_bilingual_str!(chat.lang, UNSUBSCRIBE_MESSAGE),
+		Ok(_) => get_bilingual_str!(chat.lang, UNSUBSCRIBE_ALREADY_MESSAGE),
+		Err(e) => {
+			log::error!("{e}");
+			return respond(());
+		}
+	};
+
+	reply_html!(chat_id, message.id, text, bot)?;
+
+	respond(())
+}
@@ -0,0 +1,30 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use teloxide::{prelude::*, types::ParseMode};
+
+use crate::{
+	database::{Connection, entities::chat::Chat},
+	outbound,
+	telegram::misc::{warnfilter_internal, warnfilter_message},
+};
+
+pub(super) async fn warnfilter(
+	code: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let Some(excluded) = warnfilter_internal(code.as_deref(), &chat, db_conn).await else {
+		return respond(());
+	};
+
+	let (text, ikb) = warnfilter_message(&chat, &excluded);
+
+	outbound::send(chat_id, bot.send_message(chat_id, text).parse_mode(ParseMode::Html).reply_markup(ikb)).await?;
+
+	respond(())
+}
@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use teloxide::{
@@ -8,20 +8,42 @@ use teloxide::{
 
 use super::macros::reply_html;
 use crate::{
-	answer::{Answer as _, Warning as Answer},
-	database::entities::chat::Chat,
+	answer::{self, Answer as _, Warning as Answer},
+	database::{Connection, entities::chat::Chat},
+	outbound,
 	statics::get_bilingual_str,
+	tool::types::BilingualString,
+	weather::{Warning as Data, WeatherData as _},
 };
 
-pub(super) async fn warning(message: Message, bot: Bot, chat: Chat) -> ResponseResult<()> {
+pub(super) async fn warning(
+	args: Option<String>,
+	message: Message,
+	bot: Bot,
+	chat: Chat,
+	db_conn: Connection,
+) -> ResponseResult<()> {
 	let chat_id = message.chat.id;
 
+	match args.as_deref().map(str::trim) {
+		None => {}
+		Some(arg) if arg.eq_ignore_ascii_case("diff") => return diff(message, bot, chat, db_conn).await,
+		Some(_) => {
+			let request = bot
+				.send_message(chat_id, get_bilingual_str!(chat.lang, WARNING_USAGE_MESSAGE))
+				.reply_parameters(ReplyParameters::new(message.id));
+			outbound::send(chat_id, request).await?;
+			return respond(());
+		}
+	}
+
 	let pieces = Answer::answer(&chat.lang).await;
 
 	if pieces.is_empty() {
-		bot.send_message(chat_id, get_bilingual_str!(chat.lang, NO_WARNING_MESSAGE))
-			.reply_parameters(ReplyParameters::new(message.id))
-			.await?;
+		let request = bot
+			.send_message(chat_id, get_bilingual_str!(chat.lang, NO_WARNING_MESSAGE))
+			.reply_parameters(ReplyParameters::new(message.id));
+		outbound::send(chat_id, request).await?;
 	}
 
 	for p in pieces {
@@ -30,3 +52,46 @@ pub(super) async fn warning(message: Message, bot: Bot, chat: Chat) -> ResponseR
 
 	respond(())
 }
+
+async fn diff(message: Message, bot: Bot, chat: Chat, db_conn: Connection) -> ResponseResult<()> {
+	let chat_id = message.chat.id;
+
+	let pieces = Data::get().await.map(|data| data.pieces).unwrap_or_default();
+
+	let mut diffs = Vec::new();
+	for p in &pieces {
+		let update_time = p.update_time.to_utc();
+		let previous = match db_conn.select_previous_warning_issuance(&p.code, &p.subtype, update_time).await {
+			Ok(Some(issuance)) => issuance,
+			Ok(None) => continue,
+			Err(e) => {
+				log::error!("{e}");
+				return respond(());
+			}
+		};
+
+		let contents = previous
+			.contents_zh
+			.into_iter()
+			.zip(previous.contents_en)
+			.map(|(zh, en)| BilingualString::new(zh, en))
+			.collect::<Vec<_>>();
+		diffs.extend(answer::warning_diff(&contents, p, &chat.lang));
+	}
+
+	if diffs.is_empty() {
+		let text = if pieces.is_empty() {
+			get_bilingual_str!(chat.lang, NO_WARNING_MESSAGE)
+		} else {
+			get_bilingual_str!(chat.lang, NO_WARNING_DIFF_MESSAGE)
+		};
+		let request = bot.send_message(chat_id, text).reply_parameters(ReplyParameters::new(message.id));
+		outbound::send(chat_id, request).await?;
+	}
+
+	for d in diffs.into_iter().flatten() {
+		reply_html!(chat_id, message.id, d, bot)?;
+	}
+
+	respond(())
+}
@@ -0,0 +1,18 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use teloxide::{RequestError, dispatching::UpdateHandler, prelude::*, types::ChatMemberUpdated};
+
+use crate::outbound;
+
+async fn my_chat_member(update: ChatMemberUpdated) -> ResponseResult<()> {
+	if !update.new_chat_member.is_present() {
+		outbound::forget_chat(update.chat.id).await;
+	}
+
+	respond(())
+}
+
+pub fn schema() -> UpdateHandler<RequestError> {
+	dptree::endpoint(my_chat_member)
+}
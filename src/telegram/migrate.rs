@@ -0,0 +1,16 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+use teloxide::{RequestError, dispatching::UpdateHandler, prelude::*};
+
+use crate::outbound;
+
+async fn migrate(to: ChatId, message: Message) -> ResponseResult<()> {
+	outbound::migrate_chat(message.chat.id, to).await;
+
+	respond(())
+}
+
+pub fn schema() -> UpdateHandler<RequestError> {
+	dptree::filter_map(|message: Message| message.migrate_to_chat_id().copied()).endpoint(migrate)
+}
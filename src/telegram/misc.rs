@@ -1,12 +1,64 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
-use teloxide::{prelude::*, types::InlineKeyboardButton};
+use std::fmt::Display;
 
-use crate::database::{Connection, entities::chat::Chat, types::lang::Lang};
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::Error;
+use teloxide::{
+	prelude::*,
+	types::{InlineKeyboardButton, InlineKeyboardMarkup, User},
+};
+
+use crate::{
+	database::{Connection, entities::chat::Chat, types::lang::Lang},
+	outbound,
+	statics::get_bilingual_str,
+	tool::time::HONG_KONG,
+	weather::warning as weather_warning,
+};
+
+#[derive(Clone)]
+pub struct Admins(pub Vec<UserId>);
+
+impl Admins {
+	pub fn contains(&self, message: &Message) -> bool {
+		message.from.as_ref().is_some_and(|user| self.0.contains(&user.id))
+	}
+}
+
+/// Whether chats have to opt in with /start before using other commands.
+#[derive(Clone, Copy)]
+pub struct RequireStart(pub bool);
+
+pub fn infer_lang(user: Option<&User>) -> Lang {
+	user.and_then(|u| u.language_code.as_ref().filter(|s| s.starts_with("zh")))
+		.and(Some(Lang::Chinese))
+		.unwrap_or(Lang::English)
+}
+
+/// Registers the chat, or leaves it as it is if another update of the chat has
+/// just registered it.
+pub async fn register_chat(chat_id: ChatId, user: Option<&User>, db_conn: &Connection) -> Result<Option<Chat>, Error> {
+	db_conn.insert_chat(&Chat::new(chat_id.0, infer_lang(user))).await?;
+	db_conn.select_chat(chat_id.0).await
+}
+
+/// Finds the chat, registering it unless chats have to opt in with /start.
+pub async fn find_chat(
+	chat_id: ChatId,
+	user: Option<&User>,
+	db_conn: &Connection,
+	require_start: RequireStart,
+) -> Result<Option<Chat>, Error> {
+	match db_conn.select_chat(chat_id.0).await? {
+		None if !require_start.0 => register_chat(chat_id, user, db_conn).await,
+		chat => Ok(chat),
+	}
+}
 
 pub async fn start_first(bot: Bot, chat_id: ChatId) -> ResponseResult<()> {
-	bot.send_message(chat_id, "/start first.").await?;
+	outbound::send(chat_id, bot.send_message(chat_id, "/start first.")).await?;
 
 	respond(())
 }
@@ -19,6 +71,138 @@ pub fn setlang_ikb() -> Vec<Vec<InlineKeyboardButton>> {
 	]]
 }
 
+pub fn settings_message(chat: &Chat) -> (String, InlineKeyboardMarkup) {
+	let lang = &chat.lang;
+	let on_off = |on: bool| {
+		if on { get_bilingual_str!(lang, SETTINGS_VALUE_ON) } else { get_bilingual_str!(lang, SETTINGS_VALUE_OFF) }
+	};
+
+	let settings_lang = get_bilingual_str!(lang, SETTINGS_MESSAGE_LANGUAGE);
+	let settings_pin = get_bilingual_str!(lang, SETTINGS_MESSAGE_PIN_WARNING);
+	let settings_filter = get_bilingual_str!(lang, SETTINGS_MESSAGE_WARNING_FILTER);
+	let settings_quiet = get_bilingual_str!(lang, SETTINGS_MESSAGE_QUIET_HOURS);
+	let settings_silent = get_bilingual_str!(lang, SETTINGS_MESSAGE_SILENT);
+
+	let section = |title: &str, value: &dyn Display| format!("<b>{title}</b>\n{value}");
+
+	let text = [
+		get_bilingual_str!(lang, SETTINGS_MESSAGE_1).to_string(),
+		section(settings_lang, &chat.lang),
+		section(settings_pin, &on_off(chat.pin_warning)),
+		section(settings_quiet, &quiet_hours_string(chat)),
+		section(settings_silent, &on_off(chat.silent_minor)),
+		get_bilingual_str!(lang, SETTINGS_MESSAGE_2).to_string(),
+	]
+	.join("\n\n");
+
+	let ikb = InlineKeyboardMarkup::new(vec![
+		vec![InlineKeyboardButton::callback(settings_lang, "/setlang")],
+		vec![InlineKeyboardButton::callback(
+			settings_pin,
+			if chat.pin_warning { "/pinwarning off" } else { "/pinwarning on" },
+		)],
+		vec![InlineKeyboardButton::callback(settings_filter, "/warnfilter")],
+		vec![InlineKeyboardButton::callback(
+			settings_silent,
+			if chat.silent_minor { "/silent off" } else { "/silent on" },
+		)],
+	]);
+
+	(text, ikb)
+}
+
+/// The quiet hours of the chat, e.g. `23:00 - 07:00`, or off.
+pub fn quiet_hours_string(chat: &Chat) -> String {
+	match (chat.quiet_start, chat.quiet_end) {
+		(Some(start), Some(end)) => format!("{} - {}", start.format("%H:%M"), end.format("%H:%M")),
+		_ => get_bilingual_str!(chat.lang, SETTINGS_VALUE_OFF).to_string(),
+	}
+}
+
+pub fn warnfilter_message(chat: &Chat, excluded: &[String]) -> (String, InlineKeyboardMarkup) {
+	let lang = &chat.lang;
+
+	let text = get_bilingual_str!(lang, WARNING_FILTER_MESSAGE).to_string();
+
+	let mut buttons = weather_warning::CODES
+		.iter()
+		.map(|&(code, zh, en)| {
+			let mark = if excluded.iter().any(|c| c == code) { "⬜" } else { "✅" };
+			let name = lang.map(format!("{zh} {en}"), zh.to_string(), en.to_string());
+			vec![InlineKeyboardButton::callback(format!("{mark} {name}"), format!("/warnfilter {code}"))]
+		})
+		.collect::<Vec<_>>();
+	buttons.push(vec![InlineKeyboardButton::callback(get_bilingual_str!(lang, SETTINGS_BACK), "/settings")]);
+
+	(text, InlineKeyboardMarkup::new(buttons))
+}
+
+/// Toggles whether the chat receives a warning code, returns the excluded
+/// codes afterwards.
+pub async fn warnfilter_internal(code: Option<&str>, chat: &Chat, db_conn: Connection) -> Option<Vec<String>> {
+	let mut excluded = match db_conn.select_warning_filters(chat.id).await {
+		Ok(excluded) => excluded,
+		Err(e) => {
+			log::error!("{e}");
+			return None;
+		}
+	};
+
+	let Some(code) = code.filter(|code| weather_warning::CODES.iter().any(|&(c, ..)| c == *code)) else {
+		return Some(excluded);
+	};
+
+	let res = if let Some(i) = excluded.iter().position(|c| c == code) {
+		excluded.swap_remove(i);
+		db_conn.delete_warning_filter(chat.id, code).await
+	} else {
+		excluded.push(code.to_string());
+		db_conn.insert_warning_filter(chat.id, code).await
+	};
+
+	match res {
+		Ok(_) => Some(excluded),
+		Err(e) => {
+			log::error!("{e}");
+			None
+		}
+	}
+}
+
+pub async fn pinwarning_internal(enable: bool, chat: &Chat, db_conn: Connection) -> bool {
+	if enable == chat.pin_warning {
+		return true;
+	}
+
+	let mut chat = chat.clone();
+	chat.pin_warning = enable;
+
+	match db_conn.update_chat(&chat).await {
+		Ok(res) => res.rows_affected() > 0,
+		Err(e) => {
+			log::error!("{e}");
+			false
+		}
+	}
+}
+
+pub async fn silent_internal(enable: bool, chat: &Chat, db_conn: Connection) -> bool {
+	if enable == chat.silent_minor {
+		return true;
+	}
+
+	let mut chat = chat.clone();
+	chat.silent_minor = enable;
+
+	match db_conn.update_chat(&chat).await {
+		Ok(res) => res.rows_affected() > 0,
+		Err(e) => {
+			log::error!("{e}");
+			false
+		}
+	}
+}
+
 pub async fn setlang_internal(lang: &Lang, chat: Chat, db_conn: Connection) -> bool {
 	if lang == &chat.lang {
 		return true;
@@ -35,3 +219,104 @@ pub async fn setlang_internal(lang: &Lang, chat: Chat, db_conn: Connection) -> b
 		}
 	}
 }
+
+/// Parses the end of a snooze, e.g. `2h`, `30m`, `1h30m`, `1h30` or `tomorrow`
+/// (07:00 Hong Kong Time), `off` gives `Some(None)`.
+fn parse_snooze(s: &str, now: DateTime<Utc>) -> Option<Option<DateTime<Utc>>> {
+	let s = s.trim().to_ascii_lowercase();
+
+	match s.as_str() {
+		"off" => return Some(None),
+		"tomorrow" => {
+			let tomorrow = now.with_timezone(&HONG_KONG).date_naive().succ_opt()?;
+			let until = tomorrow.and_hms_opt(7, 0, 0)?.and_local_timezone(HONG_KONG).single()?;
+			return Some(Some(until.to_utc()));
+		}
+		_ => {}
+	}
+
+	let mut minutes = 0;
+	let mut number = String::new();
+	let mut unit = None;
+	for c in s.chars() {
+		match c {
+			'0'..='9' => number.push(c),
+			'h' | 'm' => {
+				let n = number.parse::<i64>().ok()?;
+				minutes += if c == 'h' { n * 60 } else { n };
+				number.clear();
+				unit = Some(c);
+			}
+			_ => return None,
+		}
+	}
+
+	// A bare number is taken as hours, or as minutes after hours, e.g. `1h30`.
+	if !number.is_empty() {
+		let n = number.parse::<i64>().ok()?;
+		minutes += match unit {
+			None => n * 60,
+			Some('h') => n,
+			_ => return None,
+		};
+	}
+
+	// Snoozes are limited to a week.
+	(1..=7 * 24 * 60).contains(&minutes).then(|| Some(now + TimeDelta::minutes(minutes)))
+}
+
+pub fn snooze_ikb(lang: &Lang) -> InlineKeyboardMarkup {
+	InlineKeyboardMarkup::new(vec![
+		vec![
+			InlineKeyboardButton::callback(lang.map("1 小時 1 hour", "1 小時", "1 hour"), "/snooze 1h"),
+			InlineKeyboardButton::callback(lang.map("4 小時 4 hours", "4 小時", "4 hours"), "/snooze 4h"),
+		],
+		vec![InlineKeyboardButton::callback(
+			lang.map("直至明早 Until tomorrow", "直至明早", "Until tomorrow"),
+			"/snooze tomorrow",
+		)],
+		vec![InlineKeyboardButton::callback(get_bilingual_str!(lang, SETTINGS_VALUE_OFF), "/snooze off")],
+	])
+}
+
+/// Snoozes the chat as requested, returns the end of the snooze if the
+/// request is valid and saved.
+pub async fn snooze_internal(arg: &str, chat: &Chat, db_conn: Connection) -> Option<Option<DateTime<Utc>>> {
+	let until = parse_snooze(arg, Utc::now())?;
+
+	match db_conn.update_chat_snooze(chat.id, until).await {
+		Ok(_) => Some(until),
+		Err(e) => {
+			log::error!("{e}");
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use chrono::{TimeDelta, Utc};
+
+		use super::parse_snooze;
+
+		let now = Utc::now();
+		let after = |minutes| Some(Some(now + TimeDelta::minutes(minutes)));
+
+		assert_eq!(parse_snooze("30", now), after(30 * 60));
+		assert_eq!(parse_snooze("30m", now), after(30));
+		assert_eq!(parse_snooze("1h", now), after(60));
+		assert_eq!(parse_snooze("1h30", now), after(90));
+		assert_eq!(parse_snooze("2h15m", now), after(135));
+		assert_eq!(parse_snooze(" OFF ", now), Some(None));
+
+		assert_eq!(parse_snooze("", now), None);
+		assert_eq!(parse_snooze("0m", now), None);
+		assert_eq!(parse_snooze("1m30", now), None);
+		assert_eq!(parse_snooze("h", now), None);
+		assert_eq!(parse_snooze("2 hours", now), None);
+		assert_eq!(parse_snooze("8d", now), None);
+		assert_eq!(parse_snooze("169h", now), None);
+	}
+}
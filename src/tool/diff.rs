@@ -0,0 +1,108 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change<T> {
+	Same(T),
+	Added(T),
+	Removed(T),
+}
+
+/// Computes the changes from `old` to `new` by their longest common
+/// subsequence. Removals are listed before additions at the same position.
+pub fn diff<'a, T: PartialEq>(old: &'a [T], new: &'a [T]) -> Vec<Change<&'a T>> {
+	let (m, n) = (old.len(), new.len());
+
+	// lcs[i][j] is the length of the longest common subsequence of old[i..] and
+	// new[j..]
+	let mut lcs = vec![vec![0_usize; n + 1]; m + 1];
+	for i in (0..m).rev() {
+		for j in (0..n).rev() {
+			lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+		}
+	}
+
+	let mut changes = Vec::with_capacity(m.max(n));
+	let (mut i, mut j) = (0, 0);
+	while i < m && j < n {
+		if old[i] == new[j] {
+			changes.push(Change::Same(&old[i]));
+			i += 1;
+			j += 1;
+		} else if lcs[i + 1][j] >= lcs[i][j + 1] {
+			changes.push(Change::Removed(&old[i]));
+			i += 1;
+		} else {
+			changes.push(Change::Added(&new[j]));
+			j += 1;
+		}
+	}
+	changes.extend(old[i..].iter().map(Change::Removed));
+	changes.extend(new[j..].iter().map(Change::Added));
+
+	changes
+}
+
+/// Splits the text into sentences, keeping the punctuation and the following
+/// whitespace with each sentence. An English full stop only ends a sentence
+/// when a capital letter follows, so that "No. 8" stays in one piece.
+pub fn sentences(text: &str) -> Vec<&str> {
+	let mut sentences = Vec::new();
+	let mut start = 0;
+	let mut chars = text.char_indices().peekable();
+
+	while let Some((i, c)) = chars.next() {
+		let end = match c {
+			'。' | '！' | '？' | '；' => i + c.len_utf8(),
+			'.' | '!' | '?' => {
+				let rest = &text[i + 1..];
+				let trimmed = rest.trim_start();
+				if rest.len() == trimmed.len() || !trimmed.starts_with(|c: char| c.is_uppercase()) {
+					continue;
+				}
+				i + 1 + rest.len() - trimmed.len()
+			}
+			_ => continue,
+		};
+
+		sentences.push(&text[start..end]);
+		start = end;
+		while chars.peek().is_some_and(|&(i, _)| i < end) {
+			chars.next();
+		}
+	}
+
+	if start < text.len() {
+		sentences.push(&text[start..]);
+	}
+
+	sentences
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use super::{Change, diff, sentences};
+
+		let old = ["a", "b", "c"];
+		let new = ["a", "c", "d"];
+		assert_eq!(diff(&old, &new), vec![
+			Change::Same(&"a"),
+			Change::Removed(&"b"),
+			Change::Same(&"c"),
+			Change::Added(&"d"),
+		]);
+		assert_eq!(diff(&old, &[]), vec![Change::Removed(&"a"), Change::Removed(&"b"), Change::Removed(&"c")]);
+
+		assert_eq!(sentences("天文台現正發出八號信號。預料風勢增強。"), vec![
+			"天文台現正發出八號信號。",
+			"預料風勢增強。"
+		]);
+		assert_eq!(sentences("No. 8 Signal is in force. Winds will strengthen."), vec![
+			"No. 8 Signal is in force. ",
+			"Winds will strengthen."
+		]);
+		assert_eq!(sentences(""), Vec::<&str>::new());
+	}
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-identifier: MIT
+
+/// Lowercases and drops the spaces and punctuation, so that "Kwun Tong",
+/// "kwuntong" and "Kwun-Tong" are all alike.
+fn normalize(s: &str) -> Vec<char> {
+	s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+fn is_subsequence(query: &[char], name: &[char]) -> bool {
+	let mut name = name.iter();
+	query.iter().all(|q| name.any(|c| c == q))
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+	let mut row: Vec<_> = (0..=b.len()).collect();
+
+	for (i, ca) in a.iter().enumerate() {
+		let mut prev = row[0];
+		row[0] = i + 1;
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = prev + usize::from(ca != cb);
+			prev = row[j + 1];
+			row[j + 1] = substitution.min(prev + 1).min(row[j] + 1);
+		}
+	}
+
+	row[b.len()]
+}
+
+/// How well the query matches the name, the smaller the better, or `None` if
+/// it does not match at all. An exact match comes first, then a prefix, a
+/// substring, an abbreviation, and a misspelling of a few letters at last.
+pub fn score(query: &str, name: &str) -> Option<(u8, usize)> {
+	let query = normalize(query);
+	let name = normalize(name);
+
+	if query.is_empty() {
+		return None;
+	}
+
+	let extra = name.len().saturating_sub(query.len());
+
+	if query == name {
+		Some((0, 0))
+	} else if name.starts_with(&query) {
+		Some((1, extra))
+	} else if name.windows(query.len()).any(|w| w == query) {
+		Some((2, extra))
+	} else if is_subsequence(&query, &name) {
+		Some((3, extra))
+	} else {
+		let distance = edit_distance(&query, &name);
+		(distance <= query.len() / 4).then_some((4, distance))
+	}
+}
+
+/// Finds the candidate with any of its names matching the query best, the
+/// first one if there are several.
+pub fn best_match<'a, T, const N: usize>(
+	query: &str,
+	candidates: impl IntoIterator<Item = (T, [&'a str; N])>,
+) -> Option<T> {
+	candidates
+		.into_iter()
+		.filter_map(|(candidate, names)| {
+			names.iter().filter_map(|name| score(query, name)).min().map(|s| (s, candidate))
+		})
+		.min_by_key(|(s, _)| *s)
+		.map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use super::best_match;
+
+		let places = [
+			("Hong Kong Observatory", "香港天文台"),
+			("King's Park", "京士柏"),
+			("Sha Tin", "沙田"),
+			("Tseung Kwan O", "將軍澳"),
+			("Kwun Tong", "觀塘"),
+			("Tuen Mun", "屯門"),
+			("Tai Po", "大埔"),
+		];
+		let find = |query| best_match(query, places.iter().map(|&(en, zh)| (en, [zh, en])));
+
+		assert_eq!(find("kings park"), Some("King's Park"));
+		assert_eq!(find("沙田"), Some("Sha Tin"));
+		assert_eq!(find("天文台"), Some("Hong Kong Observatory"));
+		assert_eq!(find("tko"), Some("Tseung Kwan O"));
+		assert_eq!(find("Kwon Tong"), Some("Kwun Tong"));
+		assert_eq!(find("tai"), Some("Tai Po"));
+		assert_eq!(find("Mong Kok"), None);
+		assert_eq!(find(" "), None);
+	}
+}
@@ -1,10 +1,13 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-identifier: MIT
 
 pub use mix_strings::{mix_string, mix_strings};
 
 pub mod data;
+pub mod diff;
 pub mod ext;
+pub mod fuzzy;
 pub mod macros;
 mod mix_strings;
+pub mod time;
 pub mod types;
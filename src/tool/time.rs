@@ -0,0 +1,12 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+/// Hong Kong Time, which schedules, forecast dates and the times shown are all
+/// in.
+pub const HONG_KONG: FixedOffset = FixedOffset::east_opt(8 * 3600).unwrap();
+
+pub fn hong_kong_now() -> DateTime<FixedOffset> {
+	Utc::now().with_timezone(&HONG_KONG)
+}
@@ -1,61 +1,508 @@
-// Copyright (c) 2025 GreenYun Organization
+// Copyright (c) 2025 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::{
-	collections::HashMap,
-	sync::{LazyLock, OnceLock},
+	collections::{HashMap, HashSet},
+	sync::{
+		Arc, LazyLock, OnceLock,
+		atomic::{AtomicBool, Ordering},
+	},
 };
 
-use chrono::{DateTime, Utc};
-use teloxide::{prelude::*, types::ParseMode};
-use tokio::sync::RwLock;
+use chrono::Utc;
+use teloxide::{
+	ApiError, RequestError,
+	prelude::*,
+	types::{MessageId, ParseMode},
+};
+use tokio::sync::Mutex;
 
 use crate::{
-	database::types::lang::Lang,
-	tool::{mix_strings, types::BilingualString},
-	weather::{WeatherData, warning as weather_warning},
+	answer,
+	database::{
+		Connection,
+		entities::{chat::Chat, warning_delivery::WarningDelivery, warning_issuance::WarningIssuance},
+		types::lang::Lang,
+	},
+	outbound,
+	tool::{mix_string, time::hong_kong_now, types::BilingualString},
+	weather::{
+		WeatherData,
+		warning::{self as weather_warning, Warning},
+	},
+};
+
+pub use notice::{
+	check_briefing as briefing_notices, check_bulletin as bulletin_notices, check_earthquake as earthquake_notices,
 };
+pub use rain::check as rain_alerts;
+pub use schedule::run as schedule;
+pub use threshold::check as thresholds;
 
-static CHANNEL_CHAT_ID: ChatId = ChatId(-1_001_692_976_401);
 static BOT: OnceLock<Bot> = OnceLock::new();
-static LAST_UPDATE: LazyLock<RwLock<HashMap<String, DateTime<Utc>>>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+static DB: OnceLock<Connection> = OnceLock::new();
+static TRIGGER_LOCK: Mutex<()> = Mutex::const_new(());
+static CHAT_LOCKS: LazyLock<Mutex<ChatLocks>> = LazyLock::new(Mutex::default);
+static EDIT_WARNINGS: AtomicBool = AtomicBool::new(false);
+
+/// Warnings at least this severe are delivered during quiet hours and with
+/// sound, i.e. No. 8 or higher tropical cyclone signals, the Black Rainstorm
+/// Warning and the Tsunami Warning.
+const HIGH_SEVERITY: u8 = 8;
+
+type ChatLocks = HashMap<ChatId, Arc<Mutex<()>>>;
+
+/// Contents of the previous issuance of each warning re-issued, by code and
+/// subtype
+type Issuances = HashMap<(String, String), Vec<BilingualString>>;
+
+struct Destination {
+	chat_id: ChatId,
+	lang: Lang,
+	pin_warning: bool,
+	pinned_message_id: Option<i32>,
+	excluded_codes: HashSet<String>,
+	quiet: bool,
+	silent: bool,
+	snoozed: bool,
+	keywords: Vec<String>,
+}
+
+impl Destination {
+	fn new(chat: Chat) -> Self {
+		Self {
+			chat_id: ChatId(chat.id),
+			quiet: chat.is_quiet(hong_kong_now().time()),
+			silent: chat.silent_minor,
+			snoozed: chat.is_snoozed(Utc::now()),
+			lang: chat.lang,
+			pin_warning: chat.pin_warning,
+			pinned_message_id: chat.pinned_message_id,
+			excluded_codes: HashSet::new(),
+			keywords: Vec::new(),
+		}
+	}
+
+	/// Whether any of the texts is wanted, i.e. it mentions a keyword of the
+	/// destination in either language, or the destination has no keyword.
+	fn wants<'a>(&self, mut texts: impl Iterator<Item = &'a BilingualString>) -> bool {
+		self.keywords.is_empty()
+			|| texts.any(|text| {
+				let en = text.en.to_lowercase();
+				self.keywords.iter().any(|k| text.zh.contains(k.as_str()) || en.contains(&k.to_lowercase()))
+			})
+	}
+}
 
 pub fn set_bot(bot: Bot) {
 	let _ = BOT.set(bot);
 }
 
+pub fn set_db(db_conn: Connection) {
+	let _ = DB.set(db_conn);
+}
+
+pub fn set_edit_warnings(edit: bool) {
+	EDIT_WARNINGS.store(edit, Ordering::Relaxed);
+}
+
+/// Sends the texts in turn, and returns the chat they have been sent to, which
+/// is another one if the group has been upgraded to a supergroup, and the IDs
+/// of the messages sent.
+async fn send_texts(bot: &Bot, mut chat_id: ChatId, texts: Vec<String>, silent: bool) -> (ChatId, Vec<i32>) {
+	let mut message_ids = Vec::new();
+
+	for text in texts {
+		let request =
+			|chat_id| bot.send_message(chat_id, text.clone()).parse_mode(ParseMode::Html).disable_notification(silent);
+
+		let sent = match outbound::send(chat_id, request(chat_id)).await {
+			// The group has been upgraded to a supergroup, which the chat has been
+			// moved to, so the rest goes there instead.
+			Err(RequestError::MigrateToChatId(to)) => {
+				chat_id = to;
+				outbound::send(chat_id, request(chat_id)).await
+			}
+			sent => sent,
+		};
+
+		if let Ok(message) = sent {
+			message_ids.push(message.id.0);
+		}
+	}
+
+	(chat_id, message_ids)
+}
+
+/// Sends the text, or holds it for the digest while the destination is
+/// snoozed.
+async fn notify(bot: &Bot, db_conn: &Connection, dest: &Destination, text: BilingualString, silent: bool) {
+	if dest.snoozed {
+		digest::hold(db_conn, dest.chat_id, text).await;
+	} else {
+		send_texts(bot, dest.chat_id, vec![mix_string(&dest.lang, &text.as_str())], silent).await;
+	}
+}
+
+/// Edits the messages in place, and sends anew the texts whose messages cannot
+/// be edited. Returns the chat and the IDs of the messages as
+/// [`send_texts`] does.
+async fn edit_texts(
+	bot: &Bot,
+	mut chat_id: ChatId,
+	message_ids: &[i32],
+	texts: Vec<String>,
+	silent: bool,
+) -> (ChatId, Vec<i32>) {
+	if message_ids.len() != texts.len() {
+		return send_texts(bot, chat_id, texts, silent).await;
+	}
+
+	let mut edited_ids = Vec::new();
+
+	for (&id, text) in message_ids.iter().zip(texts) {
+		let request = bot.edit_message_text(chat_id, MessageId(id), text.clone()).parse_mode(ParseMode::Html);
+		match outbound::send(chat_id, request).await {
+			Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => edited_ids.push(id),
+			Err(_) => {
+				let (sent_to, sent_ids) = send_texts(bot, chat_id, vec![text], silent).await;
+				chat_id = sent_to;
+				edited_ids.extend(sent_ids);
+			}
+		}
+	}
+
+	(chat_id, edited_ids)
+}
+
+async fn update_pin(bot: &Bot, db_conn: &Connection, dest: &Destination, message_id: Option<i32>) {
+	if dest.pinned_message_id == message_id {
+		return;
+	}
+
+	if let Some(id) = dest.pinned_message_id {
+		outbound::send(dest.chat_id, bot.unpin_chat_message(dest.chat_id).message_id(MessageId(id))).await.ok();
+	}
+
+	let pinned = match message_id {
+		Some(id) => {
+			let request = bot.pin_chat_message(dest.chat_id, MessageId(id)).disable_notification(true);
+			outbound::send(dest.chat_id, request).await.ok().map(|_| id)
+		}
+		None => None,
+	};
+
+	if let Err(e) = db_conn.update_chat_pinned_message(dest.chat_id.0, pinned).await {
+		log::error!("{e}");
+	}
+}
+
+/// Finds the destinations of warnings and notices, and whether all of them are
+/// found, i.e. none of the lookups of chats failed.
+async fn destinations(db_conn: &Connection) -> (Vec<Destination>, bool) {
+	let mut destinations = Vec::new();
+	let mut complete = true;
+
+	match db_conn.select_channels().await {
+		Ok(channels) => destinations.extend(channels.into_iter().map(|channel| Destination {
+			chat_id: ChatId(channel.id),
+			lang: channel.lang,
+			pin_warning: false,
+			pinned_message_id: None,
+			excluded_codes: HashSet::new(),
+			quiet: false,
+			silent: false,
+			snoozed: false,
+			keywords: Vec::new(),
+		})),
+		Err(e) => {
+			log::error!("{e}");
+			complete = false;
+		}
+	}
+
+	let mut filters = HashMap::<i64, HashSet<String>>::new();
+	match db_conn.select_all_warning_filters().await {
+		Ok(all) => {
+			for filter in all {
+				filters.entry(filter.chat_id).or_default().insert(filter.code);
+			}
+		}
+		Err(e) => log::error!("{e}"),
+	}
+
+	let mut keywords = HashMap::<i64, Vec<String>>::new();
+	match db_conn.select_all_keywords().await {
+		Ok(all) => {
+			for keyword in all {
+				keywords.entry(keyword.chat_id).or_default().push(keyword.keyword);
+			}
+		}
+		Err(e) => log::error!("{e}"),
+	}
+
+	let mut chats = Vec::new();
+	for found in [db_conn.select_subscribed_chats().await, db_conn.select_keyword_chats().await] {
+		match found {
+			Ok(found) => chats.extend(found),
+			Err(e) => {
+				log::error!("{e}");
+				complete = false;
+			}
+		}
+	}
+
+	destinations.extend(chats.into_iter().map(|chat| Destination {
+		excluded_codes: filters.remove(&chat.id).unwrap_or_default(),
+		keywords: keywords.remove(&chat.id).unwrap_or_default(),
+		..Destination::new(chat)
+	}));
+
+	(destinations, complete)
+}
+
+/// Records the current issuance of each warning in force, and returns the
+/// contents of the previous issuance of each warning re-issued since.
+async fn record_issuances(db_conn: &Connection, pieces: &[weather_warning::Piece]) -> Issuances {
+	let mut previous = HashMap::new();
+
+	for p in pieces {
+		let update_time = p.update_time.to_utc();
+
+		match db_conn.select_previous_warning_issuance(&p.code, &p.subtype, update_time).await {
+			Ok(Some(issuance)) => {
+				// Only the previous issuance is needed for comparison.
+				let before = issuance.update_time;
+				if let Err(e) = db_conn.delete_warning_issuances_before(&p.code, &p.subtype, before).await {
+					log::error!("{e}");
+				}
+
+				let contents = issuance
+					.contents_zh
+					.into_iter()
+					.zip(issuance.contents_en)
+					.map(|(zh, en)| BilingualString::new(zh, en));
+				previous.insert((p.code.clone(), p.subtype.clone()), contents.collect());
+			}
+			Ok(None) => {}
+			Err(e) => log::error!("{e}"),
+		}
+
+		let (contents_zh, contents_en) = p.contents.iter().map(|s| (s.zh.clone(), s.en.clone())).unzip();
+		let issuance =
+			WarningIssuance { code: p.code.clone(), subtype: p.subtype.clone(), update_time, contents_zh, contents_en };
+		if let Err(e) = db_conn.insert_warning_issuance(&issuance).await {
+			log::error!("{e}");
+		}
+	}
+
+	let (codes, subtypes): (Vec<_>, Vec<_>) = pieces.iter().map(|p| (p.code.clone(), p.subtype.clone())).unzip();
+	if let Err(e) = db_conn.delete_inactive_warning_issuances(&codes, &subtypes).await {
+		log::error!("{e}");
+	}
+
+	previous
+}
+
+/// Deliveries to the same chat are made in turn, while the deliveries to a chat
+/// throttled by the rate limits do not hold up the others.
+async fn chat_lock(chat_id: ChatId) -> Arc<Mutex<()>> {
+	CHAT_LOCKS.lock().await.entry(chat_id).or_default().clone()
+}
+
 pub async fn trigger() {
-	let Some(bot) = BOT.get() else {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
 		return;
 	};
 
-	let Some(warning) = weather_warning::Warning::get().await else {
+	let Some(warning) = Warning::get().await else {
 		return;
 	};
 
-	for p in &warning.pieces {
+	// Triggers are spawned after every data update, make sure they do not race
+	// with each other on the issuances.
+	let (previous, destinations) = {
+		let _guard = TRIGGER_LOCK.lock().await;
+		(record_issuances(db_conn, &warning.pieces).await, destinations(db_conn).await)
+	};
+
+	let (destinations, complete) = destinations;
+	let chat_ids = destinations.iter().map(|dest| dest.chat_id.0).collect::<HashSet<_>>();
+
+	let warning = Arc::new(warning);
+	let previous = Arc::new(previous);
+	for dest in destinations {
+		tokio::spawn(deliver(bot, db_conn, dest, warning.clone(), previous.clone()));
+	}
+
+	// Deliveries to chats no longer subscribed are forgotten silently, unless
+	// the chats could not be looked up, which would forget them all.
+	if !complete {
+		return;
+	}
+
+	let deliveries = match db_conn.select_warning_deliveries().await {
+		Ok(deliveries) => deliveries,
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	for delivery in deliveries.into_iter().filter(|d| !chat_ids.contains(&d.chat_id)) {
+		if let Err(e) = db_conn.delete_warning_delivery(&delivery).await {
+			log::error!("{e}");
+		}
+	}
+}
+
+async fn deliver(
+	bot: &'static Bot,
+	db_conn: &'static Connection,
+	mut dest: Destination,
+	warning: Arc<Warning>,
+	previous: Arc<Issuances>,
+) {
+	let lock = chat_lock(dest.chat_id).await;
+	let _guard = lock.lock().await;
+
+	let last_update = match db_conn.select_chat_warning_deliveries(dest.chat_id.0).await {
+		Ok(deliveries) => {
+			deliveries.into_iter().map(|d| ((d.code.clone(), d.subtype.clone()), d)).collect::<HashMap<_, _>>()
+		}
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	// The most severe warning in force with a message, and its first message.
+	// Warnings held back have no message yet, so they leave the pin as it is.
+	let mut top: Option<(u8, i32)> = None;
+
+	let wanted = warning
+		.pieces
+		.iter()
+		.filter(|p| !dest.excluded_codes.contains(&p.code) && dest.wants(std::iter::once(&p.name).chain(&p.contents)))
+		.collect::<Vec<_>>();
+
+	for p in wanted {
 		let update_time = p.update_time.to_utc();
-		let name = &p.name.en;
+		let severity = p.severity();
+
+		let key = (p.code.clone(), p.subtype.clone());
+		let last = last_update.get(&key);
+		if let Some(last) = last
+			&& update_time <= last.update_time
+		{
+			if let Some(&id) = last.message_ids.first()
+				&& top.is_none_or(|(s, _)| severity > s)
+			{
+				top = Some((severity, id));
+			}
+
+			continue;
+		}
 
-		let need_send = {
-			let last_update = LAST_UPDATE.read().await;
-			last_update.get(name).is_none_or(|last| &update_time > last)
+		// Minor warnings are held back during quiet hours, and everything while
+		// snoozed, to be delivered as a digest afterwards.
+		let minor = severity < HIGH_SEVERITY;
+		let message_ids = if dest.snoozed || (dest.quiet && minor) {
+			digest::hold(db_conn, dest.chat_id, answer::warning_held(p)).await;
+			last.map(|last| last.message_ids.clone()).unwrap_or_default()
+		} else {
+			// The same code and subtype means the signal level is unchanged, so
+			// the existing messages are updated instead of posting new ones.
+			let (chat_id, message_ids) = match last {
+				Some(last) if EDIT_WARNINGS.load(Ordering::Relaxed) => {
+					let texts = answer::warning_updated(p, &dest.lang);
+					edit_texts(bot, dest.chat_id, &last.message_ids, texts, dest.silent && minor).await
+				}
+				_ => {
+					let texts = answer::warning_piece(p, &dest.lang);
+					let sent = send_texts(bot, dest.chat_id, texts, dest.silent && minor).await;
+
+					// A re-issued warning is followed by what has changed since its
+					// previous issuance, unless its messages are edited instead,
+					// which is meant to post fewer messages.
+					let diff =
+						last.and(previous.get(&key)).and_then(|contents| answer::warning_diff(contents, p, &dest.lang));
+					if let Some(diff) = diff {
+						send_texts(bot, sent.0, diff, dest.silent && minor).await;
+					}
+
+					sent
+				}
+			};
+
+			// The chat has been migrated along with its deliveries, so the rest
+			// is delivered to the supergroup, where nothing is pinned yet.
+			if chat_id != dest.chat_id {
+				dest.chat_id = chat_id;
+				dest.pinned_message_id = None;
+			}
+
+			message_ids
 		};
 
-		if need_send {
-			LAST_UPDATE.write().await.insert(name.clone(), update_time);
+		if let Some(&id) = message_ids.first()
+			&& top.is_none_or(|(s, _)| severity > s)
+		{
+			top = Some((severity, id));
+		}
+
+		let (code, subtype) = key;
+		let (name_zh, name_en) = p.name.clone().unzip();
+		let delivery =
+			WarningDelivery { chat_id: dest.chat_id.0, code, subtype, update_time, name_zh, name_en, message_ids };
+		if let Err(e) = db_conn.upsert_warning_delivery(&delivery).await {
+			log::error!("{e}");
+		}
+	}
+
+	if dest.pin_warning || dest.pinned_message_id.is_some() {
+		let message_id = top.map(|(_, id)| id).filter(|_| dest.pin_warning);
+		update_pin(bot, db_conn, &dest, message_id).await;
+	}
 
-			let mut list = vec!["<b>".to_string() + p.name.clone() + "</b>"];
-			list.extend_from_slice(&p.contents);
+	// Warnings that are no longer in force are announced as cancelled, unless
+	// the same warning is still in force with another subtype, e.g. when a
+	// tropical cyclone signal is upgraded.
+	let mut cancelled = HashSet::new();
 
-			let time_str = format!("<i>@ {}</i>", p.update_time);
-			list.push(BilingualString::new(time_str.clone(), time_str));
+	for delivery in last_update.into_values() {
+		let is_active = warning.pieces.iter().any(|p| p.code == delivery.code && p.subtype == delivery.subtype);
+		// Warnings filtered out by the destination are forgotten silently.
+		let excluded = dest.excluded_codes.contains(&delivery.code);
 
-			let chinese = mix_strings(&Lang::Chinese, &list);
-			let english = mix_strings(&Lang::English, &list);
+		if is_active && !excluded {
+			continue;
+		}
 
-			bot.send_message(CHANNEL_CHAT_ID, chinese).parse_mode(ParseMode::Html).await.ok();
-			bot.send_message(CHANNEL_CHAT_ID, english).parse_mode(ParseMode::Html).await.ok();
+		if !excluded
+			&& !warning.pieces.iter().any(|p| p.code == delivery.code)
+			&& cancelled.insert(delivery.code.clone())
+		{
+			let name = BilingualString::new(delivery.name_zh.clone(), delivery.name_en.clone());
+			let minor = weather_warning::severity(&delivery.code, &delivery.subtype) < HIGH_SEVERITY;
+			let text = answer::warning_cancelled(&name);
+			if dest.quiet && minor {
+				digest::hold(db_conn, dest.chat_id, text).await;
+			} else {
+				notify(bot, db_conn, &dest, text, dest.silent && minor).await;
+			}
+		}
+
+		// The delivery has moved along if the chat has been migrated.
+		let delivery = WarningDelivery { chat_id: dest.chat_id.0, ..delivery };
+		if let Err(e) = db_conn.delete_warning_delivery(&delivery).await {
+			log::error!("{e}");
 		}
 	}
 }
+
+mod digest;
+mod notice;
+mod rain;
+mod schedule;
+mod threshold;
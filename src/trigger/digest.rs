@@ -0,0 +1,52 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::Utc;
+use teloxide::prelude::*;
+
+use super::{BOT, DB, send_texts};
+use crate::{
+	answer,
+	database::Connection,
+	tool::{time::hong_kong_now, types::BilingualString},
+};
+
+pub(super) async fn hold(db_conn: &Connection, chat_id: ChatId, text: BilingualString) {
+	let (text_zh, text_en) = text.unzip();
+	if let Err(e) = db_conn.insert_digest(chat_id.0, &text_zh, &text_en).await {
+		log::error!("{e}");
+	}
+}
+
+/// Delivers the held notifications to chats whose quiet hours or snooze have
+/// ended.
+pub(super) async fn flush() {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
+		return;
+	};
+
+	let chats = match db_conn.select_digest_chats().await {
+		Ok(chats) => chats,
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	let time = hong_kong_now().time();
+	let now = Utc::now();
+
+	for chat in chats.into_iter().filter(|chat| !chat.is_quiet(time) && !chat.is_snoozed(now)) {
+		let entries = match db_conn.take_digests(chat.id).await {
+			Ok(digests) => digests.into_iter().map(|d| BilingualString::new(d.text_zh, d.text_en)).collect::<Vec<_>>(),
+			Err(e) => {
+				log::error!("{e}");
+				continue;
+			}
+		};
+
+		if !entries.is_empty() {
+			send_texts(bot, ChatId(chat.id), vec![answer::digest(&entries, &chat.lang)], chat.silent_minor).await;
+		}
+	}
+}
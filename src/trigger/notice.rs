@@ -0,0 +1,141 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{TimeDelta, Utc};
+
+use super::{BOT, DB, Destination, destinations, notify};
+use crate::{
+	answer,
+	database::{Connection, entities::notice::Notice, types::notice_kind::NoticeKind},
+	tool::types::BilingualString,
+	weather::{Briefing, Bulletin, Earthquake, WeatherData as _, warning::Warning},
+};
+
+/// Felt reports older than this are not pushed, e.g. the last one fetched
+/// after a restart.
+const FELT_REPORT_EXPIRY: TimeDelta = TimeDelta::hours(24);
+
+/// When none of a kind is fetched, which may be a glitch of the data rather
+/// than the notices being withdrawn, those pushed are only forgotten after
+/// this long.
+const NOTICE_RETENTION: TimeDelta = TimeDelta::hours(24);
+
+/// The warning that the notices of the kind repeat, so that they follow the
+/// warning filters of the destinations.
+const fn warning_code(kind: NoticeKind) -> Option<&'static str> {
+	match kind {
+		NoticeKind::FireDanger => Some("WFIRE"),
+		_ => None,
+	}
+}
+
+/// Felt earthquakes are pushed to the chats opted in, other notices to all
+/// destinations.
+async fn recipients(db_conn: &Connection, kind: NoticeKind) -> Vec<Destination> {
+	match kind {
+		NoticeKind::FeltEarthquake => match db_conn.select_earthquake_alert_chats().await {
+			Ok(chats) => chats.into_iter().map(Destination::new).collect(),
+			Err(e) => {
+				log::error!("{e}");
+				Vec::new()
+			}
+		},
+		_ => destinations(db_conn).await.0,
+	}
+}
+
+/// Pushes the notices of a kind that have not been pushed yet, and forgets
+/// those no longer present so that they are pushed again if they reappear.
+async fn push(kind: NoticeKind, current: Vec<BilingualString>) {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
+		return;
+	};
+
+	let pushed = match db_conn.select_notices(kind).await {
+		Ok(pushed) => pushed,
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	let current = current
+		.into_iter()
+		.filter(|text| !text.is_empty())
+		.map(|text| {
+			let (text_zh, text_en) = text.unzip();
+			Notice { kind, text_zh, text_en }
+		})
+		.collect::<Vec<_>>();
+
+	let is_same = |a: &Notice, b: &Notice| a.text_zh == b.text_zh && a.text_en == b.text_en;
+
+	let new = current.iter().filter(|n| !pushed.iter().any(|p| is_same(n, p))).collect::<Vec<_>>();
+	if !new.is_empty() {
+		let code = warning_code(kind);
+
+		// Notices of a warning in force have been delivered as the warning, so
+		// they are only recorded as pushed.
+		let in_force = match code {
+			Some(code) => Warning::get().await.is_some_and(|w| w.pieces.iter().any(|p| p.code == code)),
+			None => false,
+		};
+		let destinations = if in_force { Vec::new() } else { recipients(db_conn, kind).await };
+
+		for notice in new {
+			let text = BilingualString::new(notice.text_zh.clone(), notice.text_en.clone());
+
+			let wanted = |dest: &&Destination| {
+				code.is_none_or(|code| !dest.excluded_codes.contains(code)) && dest.wants(std::iter::once(&text))
+			};
+			for dest in destinations.iter().filter(wanted) {
+				notify(bot, db_conn, dest, answer::notice(kind, &text), dest.silent).await;
+			}
+
+			if let Err(e) = db_conn.insert_notice(notice).await {
+				log::error!("{e}");
+			}
+		}
+	}
+
+	if current.is_empty() {
+		if let Err(e) = db_conn.delete_notices_pushed_before(kind, Utc::now() - NOTICE_RETENTION).await {
+			log::error!("{e}");
+		}
+
+		return;
+	}
+
+	for notice in pushed.iter().filter(|p| !current.iter().any(|n| is_same(n, p))) {
+		if let Err(e) = db_conn.delete_notice(notice).await {
+			log::error!("{e}");
+		}
+	}
+}
+
+pub async fn check_bulletin() {
+	let Some(data) = Bulletin::get().await else {
+		return;
+	};
+
+	push(NoticeKind::SpecialTip, data.special_tips).await;
+	push(NoticeKind::RainstormReminder, vec![data.rainstorm_reminder]).await;
+}
+
+pub async fn check_briefing() {
+	let Some(data) = Briefing::get().await else {
+		return;
+	};
+
+	push(NoticeKind::FireDanger, vec![data.fire_danger_warning]).await;
+}
+
+pub async fn check_earthquake() {
+	let Some(data) = Earthquake::get().await else {
+		return;
+	};
+
+	let felt = data.felt.filter(|r| Utc::now() - r.update_time.to_utc() < FELT_REPORT_EXPIRY);
+
+	push(NoticeKind::FeltEarthquake, felt.iter().map(answer::earthquake_report).collect()).await;
+}
@@ -0,0 +1,62 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use super::{BOT, DB, Destination, notify};
+use crate::{
+	answer,
+	tool::time::hong_kong_now,
+	weather::{Forecast, WeatherData as _},
+};
+
+const PSR_HIGH: i16 = 5;
+
+/// Alerts when tomorrow's probability of significant rain becomes high, or is
+/// raised compared with the previous forecast.
+fn should_alert(previous: Option<i16>, level: i16) -> bool {
+	previous.map_or(level >= PSR_HIGH, |previous| level > previous)
+}
+
+pub async fn check() {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
+		return;
+	};
+
+	let Some(data) = Forecast::get().await else {
+		return;
+	};
+
+	let today = hong_kong_now().date_naive();
+	let tomorrow = data.daily.iter().find(|d| today.succ_opt() == Some(d.date));
+
+	if let Some(day) = tomorrow {
+		let previous = match db_conn.select_psr(day.date).await {
+			Ok(previous) => previous,
+			Err(e) => {
+				log::error!("{e}");
+				return;
+			}
+		};
+
+		if should_alert(previous, day.psr_level.into()) {
+			match db_conn.select_rain_alert_chats().await {
+				Ok(chats) => {
+					for dest in chats.into_iter().map(Destination::new) {
+						let text = answer::rain_alert(day, previous.is_some());
+						notify(bot, db_conn, &dest, text, dest.silent).await;
+					}
+				}
+				Err(e) => log::error!("{e}"),
+			}
+		}
+	}
+
+	for day in &data.daily {
+		if let Err(e) = db_conn.upsert_psr(day.date, day.psr_level.into()).await {
+			log::error!("{e}");
+		}
+	}
+
+	if let Err(e) = db_conn.delete_psr_before(today).await {
+		log::error!("{e}");
+	}
+}
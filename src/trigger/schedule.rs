@@ -0,0 +1,111 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use chrono::{NaiveTime, Timelike, Utc};
+use teloxide::prelude::*;
+use tokio::{signal::ctrl_c, time::sleep};
+
+use super::{BOT, DB, digest, send_texts};
+use crate::{
+	answer::{self, Answer as _, Briefing, Bulletin, Forecast},
+	database::types::{lang::Lang, product::Product},
+	tool::time::hong_kong_now,
+};
+
+async fn product_texts(product: Product, lang: &Lang) -> Option<Vec<String>> {
+	let texts = match product {
+		Product::Briefing => Briefing::answer(lang).await,
+		Product::Bulletin => Bulletin::answer(lang).await,
+		Product::Forecast => Forecast::answer(lang).await,
+	};
+
+	if answer::is_err(&texts, lang) {
+		return None;
+	}
+
+	let texts = texts.into_iter().filter(|text| !text.is_empty()).collect::<Vec<_>>();
+	(!texts.is_empty()).then_some(texts)
+}
+
+async fn deliver(now: NaiveTime) {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
+		return;
+	};
+
+	let due = match db_conn.select_due_schedules(now).await {
+		Ok(due) => due,
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	for schedule in due {
+		let chat_id = ChatId(schedule.chat.id);
+
+		if schedule.chat.is_snoozed(Utc::now()) {
+			digest::hold(db_conn, chat_id, answer::skipped(schedule.product)).await;
+			continue;
+		}
+
+		let Some(mut texts) = product_texts(schedule.product, &schedule.chat.lang).await else {
+			log::error!("{:?} data is unavailable for scheduled delivery", schedule.product);
+			continue;
+		};
+
+		if schedule.product == Product::Briefing {
+			let lunar = match db_conn.select_lunar_briefing(schedule.chat.id).await {
+				Ok(lunar) => lunar,
+				Err(e) => {
+					log::error!("{e}");
+					false
+				}
+			};
+
+			if lunar
+				&& let Some(line) = answer::lunar_briefing_line(&schedule.chat.lang).await
+				&& let Some(text) = texts.last_mut()
+			{
+				*text = format!("{text}\n\n{line}");
+			}
+		}
+
+		send_texts(bot, chat_id, texts, schedule.chat.silent_minor).await;
+	}
+}
+
+/// Delivers the scheduled products at the start of every minute.
+pub async fn run() {
+	loop {
+		let wait = 60 - Utc::now().second();
+
+		tokio::select! {
+			r = ctrl_c() => {
+				if let Err(e) = r {
+					log::error!("failed to listen for ^C signal: {e}");
+					continue;
+				}
+
+				log::info!("^C received, scheduler will be shut down");
+				break;
+			}
+
+			() = sleep(Duration::from_secs(wait.into())) => {
+				// continue to delivery
+			}
+		}
+
+		// Deliveries may take longer than a minute while throttled, so they are
+		// made in the background not to miss the schedules of the next minute.
+		let Some(now) = hong_kong_now().time().with_second(0).and_then(|t| t.with_nanosecond(0)) else {
+			continue;
+		};
+
+		tokio::spawn(async move {
+			deliver(now).await;
+			digest::flush().await;
+		});
+	}
+}
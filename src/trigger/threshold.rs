@@ -0,0 +1,100 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use super::{BOT, DB, Destination, notify};
+use crate::{
+	answer,
+	database::{
+		entities::threshold::{ChatThreshold, Threshold},
+		types::metric::Metric,
+	},
+	weather::{Bulletin, WeatherData as _},
+};
+
+fn reading(metric: Metric, data: &Bulletin) -> Option<f32> {
+	match metric {
+		Metric::Temperature => data.temperature,
+		Metric::Humidity => data.humidity,
+		Metric::UVIndex => data.uv_index.as_ref().map(|uv| uv.value),
+	}
+}
+
+/// Once triggered, a rule stays so until the reading falls back beyond the
+/// metric's margin, so that readings hovering around the value do not flap.
+fn next_triggered(t: &Threshold, reading: f32) -> bool {
+	let margin = t.metric.margin();
+	let (crossed, cleared) = if t.above {
+		(reading >= t.value, reading < t.value - margin)
+	} else {
+		(reading <= t.value, reading > t.value + margin)
+	};
+
+	if crossed {
+		true
+	} else if cleared {
+		false
+	} else {
+		t.triggered
+	}
+}
+
+/// Notifies chats whose thresholds have been crossed by the latest bulletin.
+pub async fn check() {
+	let (Some(bot), Some(db_conn)) = (BOT.get(), DB.get()) else {
+		return;
+	};
+
+	let Some(data) = Bulletin::get().await else {
+		return;
+	};
+
+	let rules = match db_conn.select_chat_thresholds().await {
+		Ok(rules) => rules,
+		Err(e) => {
+			log::error!("{e}");
+			return;
+		}
+	};
+
+	for ChatThreshold { chat, mut threshold } in rules {
+		let Some(reading) = reading(threshold.metric, &data) else {
+			continue;
+		};
+
+		let triggered = next_triggered(&threshold, reading);
+		if triggered == threshold.triggered {
+			continue;
+		}
+
+		if triggered {
+			let dest = Destination::new(chat);
+			notify(bot, db_conn, &dest, answer::threshold_alert(&threshold, reading), dest.silent).await;
+		}
+
+		threshold.triggered = triggered;
+		if let Err(e) = db_conn.update_threshold_triggered(&threshold).await {
+			log::error!("{e}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use super::{Metric, Threshold, next_triggered};
+
+		let mut t = Threshold { chat_id: 0, metric: Metric::Temperature, above: true, value: 33.0, triggered: false };
+
+		assert!(!next_triggered(&t, 32.9));
+		assert!(next_triggered(&t, 33.0));
+
+		t.triggered = true;
+		assert!(next_triggered(&t, 32.5));
+		assert!(!next_triggered(&t, 31.9));
+
+		let t = Threshold { chat_id: 0, metric: Metric::Humidity, above: false, value: 40.0, triggered: true };
+		assert!(next_triggered(&t, 44.0));
+		assert!(!next_triggered(&t, 45.5));
+	}
+}
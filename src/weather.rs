@@ -1,7 +1,7 @@
 // Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
-use std::{any::type_name, sync::OnceLock};
+use std::{any::type_name, fmt::Display, str::FromStr, sync::OnceLock};
 
 use hko::{common::Lang, fetch_with_client};
 use tokio::{
@@ -41,7 +41,10 @@ trait WeatherDataUpdater: WeatherData + From<(Self::Source, Self::Source)> {
 	type Source;
 
 	async fn update(chinese: Self::Source, english: Self::Source) {
-		let translated = Self::from((chinese, english));
+		Self::store(Self::from((chinese, english))).await;
+	}
+
+	async fn store(translated: Self) {
 		if let Some(lock) = Self::get_store().get() {
 			let mut lock = lock.write().await;
 			*lock = translated;
@@ -49,6 +52,11 @@ trait WeatherDataUpdater: WeatherData + From<(Self::Source, Self::Source)> {
 			Self::get_store().set(RwLock::new(translated)).ok();
 		}
 	}
+
+	/// Called once the store has been refreshed.
+	fn on_update() {
+		tokio::spawn(trigger::trigger());
+	}
 }
 
 // This allow notation is not good, but we are trying not to use the
@@ -67,12 +75,44 @@ where
 
 	if let (Ok(chinese), Ok(english)) = (chinese, english) {
 		T::update(chinese, english).await;
-		tokio::spawn(trigger::trigger());
+		T::on_update();
 	} else {
 		log::error!("failed to fetch data for {}", type_name::<T>());
 	}
 }
 
+/// Fetches a dataset from HKO open data in JSON. The URL is built here, as the
+/// URL functions of `hko::opendata` reject the years after its release, and
+/// only its parsers are used.
+async fn fetch_open_data<T>(query: &str) -> Option<T>
+where
+	T: FromStr,
+	T::Err: Display,
+{
+	fetch_parsed(&format!("https://data.weather.gov.hk/weatherAPI/opendata/opendata.php?{query}&rformat=json")).await
+}
+
+/// Fetches the text at the URL and parses it.
+async fn fetch_parsed<T>(url: &str) -> Option<T>
+where
+	T: FromStr,
+	T::Err: Display,
+{
+	let data = match http::client().get(url).send().await {
+		Ok(response) => response.text().await.map_err(|e| e.to_string()),
+		Err(e) => Err(e.to_string()),
+	}
+	.and_then(|text| T::from_str(&text).map_err(|e| e.to_string()));
+
+	match data {
+		Ok(data) => Some(data),
+		Err(e) => {
+			log::error!("failed to fetch {}: {e}", type_name::<T>());
+			None
+		}
+	}
+}
+
 pub async fn update() {
 	const UPDATE_PERIOD: u64 = 300;
 
@@ -110,9 +150,13 @@ pub async fn update() {
 macros::weather_mods! {
 	pub mod briefing;
 	pub mod bulletin;
+	pub mod earthquake;
 	pub mod forecast;
 	pub mod warning;
 	const ALL_UPDATERS: [&Updater; COUNT];
 }
 
+pub mod astronomy;
+pub mod lunar;
 mod macros;
+pub mod tide;
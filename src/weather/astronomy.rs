@@ -0,0 +1,141 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, str::FromStr, sync::LazyLock};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone as _, Utc};
+use tokio::sync::RwLock;
+
+use super::fetch_open_data;
+
+/// Mean length of the synodic month, in days
+const SYNODIC_MONTH: f64 = 29.530_588_853;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Body {
+	Sun,
+	Moon,
+}
+
+/// The times of a day, each of which is `None` if it does not happen on the
+/// day, as the moon rises and sets about 50 minutes later every day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiseSet {
+	pub date: NaiveDate,
+	pub rise: Option<NaiveTime>,
+	pub transit: Option<NaiveTime>,
+	pub set: Option<NaiveTime>,
+}
+
+/// The rows of a year, parsed here as the parsers of `hko::opendata` leave out
+/// the days without any of the times.
+struct Table(Vec<RiseSet>);
+
+impl FromStr for Table {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (_, data) = s.split_once("\"data\"").ok_or("data not found")?;
+
+		let rows = data
+			.split(']')
+			.filter_map(|row| {
+				let (_, row) = row.rsplit_once('[')?;
+				let mut fields = row.split(',').map(|field| field.trim().trim_matches('"'));
+
+				let date = NaiveDate::parse_from_str(fields.next()?, "%Y-%m-%d").ok()?;
+				let mut time = || fields.next().and_then(|field| NaiveTime::parse_from_str(field, "%H:%M").ok());
+				Some(RiseSet { date, rise: time(), transit: time(), set: time() })
+			})
+			.collect();
+
+		Ok(Self(rows))
+	}
+}
+
+/// Times keyed by body and year
+type Tables = HashMap<(Body, i32), Vec<RiseSet>>;
+
+static CACHE: LazyLock<RwLock<Tables>> = LazyLock::new(RwLock::default);
+
+async fn fetch(body: Body, year: i32) -> Option<Vec<RiseSet>> {
+	let data_type = match body {
+		Body::Sun => "SRS",
+		Body::Moon => "MRS",
+	};
+
+	let Table(rows) = fetch_open_data(&format!("dataType={data_type}&year={year}")).await?;
+	Some(rows)
+}
+
+/// Gets the times of the body on the date, the inner `None` means the times
+/// are not available on the date.
+pub async fn get(body: Body, date: NaiveDate) -> Option<Option<RiseSet>> {
+	let key = (body, date.year());
+
+	let cached = CACHE.read().await.get(&key).cloned();
+	let yearly = if let Some(yearly) = cached {
+		yearly
+	} else {
+		let yearly = fetch(body, date.year()).await?;
+		CACHE.write().await.insert(key, yearly.clone());
+		yearly
+	};
+
+	Some(yearly.into_iter().find(|t| t.date == date))
+}
+
+/// The age of the moon in days since the last new moon, reckoned from the new
+/// moon of 6 January 2000, 18:14 UTC.
+pub fn moon_age(time: DateTime<Utc>) -> f64 {
+	let epoch = Utc.with_ymd_and_hms(2000, 1, 6, 18, 14, 0).unwrap();
+
+	#[allow(clippy::cast_precision_loss)]
+	let days = (time - epoch).num_seconds() as f64 / 86400.0;
+
+	days.rem_euclid(SYNODIC_MONTH)
+}
+
+/// One of the eight phases of the moon, from 0 for the new moon to 4 for the
+/// full moon.
+pub fn moon_phase(time: DateTime<Utc>) -> u8 {
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let phase = (moon_age(time) / SYNODIC_MONTH * 8.0).round() as u8;
+
+	phase % 8
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use std::str::FromStr as _;
+
+		use chrono::{NaiveDate, NaiveTime, TimeZone as _, Utc};
+
+		use super::{RiseSet, Table, moon_phase};
+
+		// The new moon of the total solar eclipse, and the full moon after it
+		assert_eq!(moon_phase(Utc.with_ymd_and_hms(2024, 4, 8, 18, 21, 0).unwrap()), 0);
+		assert_eq!(moon_phase(Utc.with_ymd_and_hms(2024, 4, 23, 23, 49, 0).unwrap()), 4);
+		assert_eq!(moon_phase(Utc.with_ymd_and_hms(2024, 4, 15, 19, 13, 0).unwrap()), 2);
+		assert_eq!(moon_phase(Utc.with_ymd_and_hms(2024, 5, 1, 11, 27, 0).unwrap()), 6);
+
+		let Table(rows) = Table::from_str(
+			r#"{"fields":["YYYY-MM-DD","RISE","TRAN.","SET"],"data":[["2026-01-09","23:26","05:31","11:36"],
+			["2026-01-10","","06:15","12:10"],["2026-01-11","00:21","06:58",""]]}"#,
+		)
+		.unwrap();
+
+		let time = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+		assert_eq!(rows.len(), 3);
+		assert_eq!(rows[1], RiseSet {
+			date: NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+			rise: None,
+			transit: time(6, 15),
+			set: time(12, 10),
+		});
+		assert_eq!(rows[2].rise, time(0, 21));
+		assert_eq!(rows[2].set, None);
+	}
+}
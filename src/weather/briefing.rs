@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::sync::OnceLock;
@@ -7,7 +7,7 @@ use chrono::{DateTime, FixedOffset};
 use hko::weather::Local as Source;
 use tokio::sync::RwLock;
 
-use crate::tool::types::BilingualString;
+use crate::{tool::types::BilingualString, trigger};
 
 use super::{WeatherData, WeatherDataUpdater};
 
@@ -52,4 +52,9 @@ impl WeatherData for Briefing {
 
 impl WeatherDataUpdater for Briefing {
 	type Source = Source;
+
+	fn on_update() {
+		tokio::spawn(trigger::trigger());
+		tokio::spawn(trigger::briefing_notices());
+	}
 }
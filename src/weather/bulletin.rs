@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::sync::OnceLock;
@@ -7,7 +7,7 @@ use chrono::{DateTime, FixedOffset};
 use hko::weather::{Current as Source, Name as WeatherName};
 use tokio::sync::RwLock;
 
-use crate::tool::types::BilingualString;
+use crate::{tool::types::BilingualString, trigger};
 
 use super::{WeatherData, WeatherDataUpdater};
 
@@ -19,10 +19,37 @@ pub struct BulletinUVIndex {
 	pub period: BilingualString,
 }
 
+/// Temperature recorded at a station
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Default)]
+pub struct BulletinTemperature {
+	pub place: BilingualString,
+	pub value: f32,
+}
+
+/// Rainfall in millimetres recorded in a district, where `min` is only given
+/// when the readings in the district spread over a range.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Default)]
+pub struct BulletinRainfall {
+	pub place: BilingualString,
+	pub min: Option<f32>,
+	pub max: Option<f32>,
+	pub maintenance: bool,
+}
+
 #[derive(Clone, Default)]
 pub struct Bulletin {
-	pub temperature: f32,
-	pub humidity: f32,
+	/// Temperature at the Observatory, missing when the station reports none
+	pub temperature: Option<f32>,
+	pub temperatures: Vec<BulletinTemperature>,
+	pub temperature_time: DateTime<FixedOffset>,
+	pub rainfall: Vec<BulletinRainfall>,
+	pub rainfall_start_time: DateTime<FixedOffset>,
+	pub rainfall_end_time: DateTime<FixedOffset>,
+	/// Relative humidity at the Observatory, missing when the station reports
+	/// none
+	pub humidity: Option<f32>,
 	pub uv_index: Option<BulletinUVIndex>,
 	pub weather_icon: Vec<WeatherName>,
 	pub warning: Vec<BilingualString>,
@@ -34,6 +61,20 @@ pub struct Bulletin {
 
 static STORE: OnceLock<RwLock<Bulletin>> = OnceLock::new();
 
+/// Pairs the Chinese and English names of the places, which carry no code and
+/// are listed in the same order in both languages. Should the lists ever
+/// differ, the English names are used for both rather than mismatching them.
+fn place_names<'a>(zh: impl Iterator<Item = &'a str>, en: impl Iterator<Item = &'a str>) -> Vec<BilingualString> {
+	let (zh, en): (Vec<_>, Vec<_>) = (zh.collect(), en.collect());
+
+	if zh.len() == en.len() {
+		zh.into_iter().zip(en).map(|(c, e)| BilingualString::new(c, e)).collect()
+	} else {
+		log::warn!("mismatched place lists: {} in Chinese, {} in English", zh.len(), en.len());
+		en.into_iter().map(|e| BilingualString::new(e, e)).collect()
+	}
+}
+
 impl Bulletin {
 	fn new(zh: Source, en: Source) -> Self {
 		let get_uv_index = || {
@@ -68,15 +109,32 @@ impl Bulletin {
 			temperature: en
 				.temperature
 				.data
-				.into_iter()
-				.find_map(|v| v.place.eq("Hong Kong Observatory").then_some(v.value))
-				.unwrap_or_default(),
+				.iter()
+				.find_map(|v| v.place.eq("Hong Kong Observatory").then_some(v.value)),
+			temperatures: place_names(
+				zh.temperature.data.iter().map(|v| v.place.as_str()),
+				en.temperature.data.iter().map(|v| v.place.as_str()),
+			)
+			.into_iter()
+			.zip(&en.temperature.data)
+			.map(|(place, e)| BulletinTemperature { place, value: e.value })
+			.collect(),
+			temperature_time: en.temperature.record_time,
+			rainfall: place_names(
+				zh.rainfall.data.iter().map(|v| v.place.as_str()),
+				en.rainfall.data.iter().map(|v| v.place.as_str()),
+			)
+			.into_iter()
+			.zip(&en.rainfall.data)
+			.map(|(place, e)| BulletinRainfall { place, min: e.min, max: e.max, maintenance: e.maintenance })
+			.collect(),
+			rainfall_start_time: en.rainfall.start_time,
+			rainfall_end_time: en.rainfall.end_time,
 			humidity: en
 				.humidity
 				.data
 				.into_iter()
-				.find_map(|v| v.place.eq("Hong Kong Observatory").then_some(v.value))
-				.unwrap_or_default(),
+				.find_map(|v| v.place.eq("Hong Kong Observatory").then_some(v.value)),
 			uv_index: get_uv_index(),
 			weather_icon: zh.icon.icon,
 			warning: zh
@@ -119,4 +177,10 @@ impl WeatherData for Bulletin {
 
 impl WeatherDataUpdater for Bulletin {
 	type Source = Source;
+
+	fn on_update() {
+		tokio::spawn(trigger::trigger());
+		tokio::spawn(trigger::thresholds());
+		tokio::spawn(trigger::bulletin_notices());
+	}
 }
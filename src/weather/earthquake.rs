@@ -0,0 +1,108 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, FixedOffset};
+use hko::{
+	common::Lang,
+	earthquake::{FeltReport as Source, Message},
+	fetch_with_client,
+};
+use tokio::sync::RwLock;
+
+use crate::{http, tool::types::BilingualString, trigger};
+
+use super::{WeatherData, WeatherDataUpdater};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct EarthquakeReport {
+	pub region: BilingualString,
+	pub magnitude: f64,
+	pub latitude: f64,
+	pub longitude: f64,
+	/// The maximum intensity in Hong Kong on the Modified Mercalli Scale, only
+	/// for felt reports.
+	pub intensity: Option<i64>,
+	pub details: Vec<BilingualString>,
+	pub occur_time: DateTime<FixedOffset>,
+	pub update_time: DateTime<FixedOffset>,
+}
+
+#[derive(Clone, Default)]
+pub struct Earthquake {
+	/// The latest quick earthquake message
+	pub message: Option<EarthquakeReport>,
+	/// The latest locally felt earth tremor report
+	pub felt: Option<EarthquakeReport>,
+}
+
+static STORE: OnceLock<RwLock<Earthquake>> = OnceLock::new();
+
+impl EarthquakeReport {
+	fn from_message(zh: Message, en: Message) -> Self {
+		Self {
+			region: BilingualString::new(zh.region, en.region),
+			magnitude: zh.magnitude,
+			latitude: zh.latitude,
+			longitude: zh.longitude,
+			intensity: None,
+			details: Vec::new(),
+			occur_time: zh.occur_time,
+			update_time: zh.update_time,
+		}
+	}
+
+	/// The felt report is empty when there is no recent felt earthquake.
+	fn from_felt(zh: Source, en: Source) -> Option<Self> {
+		let details = zh.details.unwrap_or_default().into_iter().zip(en.details.unwrap_or_default());
+
+		Some(Self {
+			region: BilingualString::new(zh.region?, en.region?),
+			magnitude: zh.magnitude?,
+			latitude: zh.latitude?,
+			longitude: zh.longitude?,
+			intensity: zh.intensity,
+			details: details.map(|(zh, en)| BilingualString::new(zh, en)).collect(),
+			occur_time: zh.occur_time?,
+			update_time: zh.update_time?,
+		})
+	}
+}
+
+impl From<(Source, Source)> for Earthquake {
+	fn from((zh, en): (Source, Source)) -> Self {
+		Self { message: None, felt: EarthquakeReport::from_felt(zh, en) }
+	}
+}
+
+impl WeatherData for Earthquake {
+	fn get_store() -> &'static OnceLock<RwLock<Self>> {
+		&STORE
+	}
+}
+
+impl WeatherDataUpdater for Earthquake {
+	type Source = Source;
+
+	/// The quick earthquake message comes from another feed, which is fetched
+	/// along with the felt report.
+	async fn update(chinese: Source, english: Source) {
+		let mut data = Self::from((chinese, english));
+
+		let chinese = fetch_with_client::<Message>(Lang::TC, http::client()).await;
+		let english = fetch_with_client::<Message>(Lang::EN, http::client()).await;
+
+		match (chinese, english) {
+			(Ok(chinese), Ok(english)) => data.message = Some(EarthquakeReport::from_message(chinese, english)),
+			_ => log::error!("failed to fetch the quick earthquake message"),
+		}
+
+		Self::store(data).await;
+	}
+
+	fn on_update() {
+		tokio::spawn(trigger::earthquake_notices());
+	}
+}
@@ -1,13 +1,13 @@
-// Copyright (c) 2024 - 2025 GreenYun Organization
+// Copyright (c) 2024 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::sync::OnceLock;
 
 use chrono::{DateTime, FixedOffset, NaiveDate};
-use hko::weather::{nine_day::WeatherForcast, NineDay as Source};
+use hko::weather::{NineDay as Source, PSR, nine_day::WeatherForcast};
 use tokio::sync::RwLock;
 
-use crate::tool::types::BilingualString;
+use crate::{tool::types::BilingualString, trigger};
 
 use super::{WeatherData, WeatherDataUpdater};
 
@@ -20,6 +20,18 @@ pub struct DailyForecast {
 	pub temp: (f32, f32),
 	pub rh: (f32, f32),
 	pub psr: BilingualString,
+	/// Probability of significant rain ranked from 1 (low) to 5 (high).
+	pub psr_level: u8,
+}
+
+const fn psr_level(psr: PSR) -> u8 {
+	match psr {
+		PSR::High => 5,
+		PSR::MediumHigh => 4,
+		PSR::Medium => 3,
+		PSR::MediumLow => 2,
+		PSR::Low => 1,
+	}
 }
 
 impl DailyForecast {
@@ -34,6 +46,7 @@ impl DailyForecast {
 			wind: BilingualString::new(zh.wind, en.wind),
 			temp: (zh.min_temp.value, zh.max_temp.value),
 			rh: (zh.min_humidity.value, zh.max_humidity.value),
+			psr_level: psr_level(psr),
 			psr: BilingualString::new(psr_zh, psr_en),
 		}
 	}
@@ -77,4 +90,9 @@ impl WeatherData for Forecast {
 
 impl WeatherDataUpdater for Forecast {
 	type Source = Source;
+
+	fn on_update() {
+		tokio::spawn(trigger::trigger());
+		tokio::spawn(trigger::rain_alerts());
+	}
 }
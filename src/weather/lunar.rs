@@ -0,0 +1,141 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, str::FromStr, sync::LazyLock};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, Utc};
+use tokio::sync::RwLock;
+
+use super::fetch_parsed;
+
+#[derive(Clone)]
+pub struct LunarDate {
+	/// Lunar year with its stem-branch and zodiac, e.g. 丙午年，馬
+	pub year: String,
+	/// e.g. 九月初八
+	pub date: String,
+}
+
+impl FromStr for LunarDate {
+	type Err = &'static str;
+
+	/// Parses the response, a JSON object of two strings, which is all that is
+	/// needed of JSON here.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let field = |name| json_string(s, name).ok_or("lunar date not found");
+
+		Ok(Self { year: field("LunarYear")?, date: field("LunarDate")? })
+	}
+}
+
+/// Reads the string of the field, unescaping the characters escaped as
+/// `\uXXXX`.
+fn json_string(s: &str, field: &str) -> Option<String> {
+	let (_, value) = s.split_once(&format!("\"{field}\""))?;
+	let value = value.trim_start().strip_prefix(':')?.trim_start().strip_prefix('"')?;
+
+	let mut string = String::new();
+	let mut chars = value.chars();
+	loop {
+		match chars.next()? {
+			'"' => return Some(string),
+			'\\' => match chars.next()? {
+				'u' => {
+					let code = chars.by_ref().take(4).collect::<String>();
+					string.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+				}
+				'n' => string.push('\n'),
+				c => string.push(c),
+			},
+			c => string.push(c),
+		}
+	}
+}
+
+/// Conversions once fetched, which are kept for good.
+static CACHE: LazyLock<RwLock<HashMap<NaiveDate, LunarDate>>> = LazyLock::new(RwLock::default);
+
+/// Whether the conversion of the date is provided by the Observatory, which
+/// covers 1901 to 2100.
+pub fn is_supported(date: NaiveDate) -> bool {
+	(1901..=2100).contains(&date.year())
+}
+
+pub async fn get(date: NaiveDate) -> Option<LunarDate> {
+	if let Some(lunar) = CACHE.read().await.get(&date) {
+		return Some(lunar.clone());
+	}
+
+	// The URL is built here, as `hko::lunardate::url` only accepts the years
+	// from 2023 to 2027.
+	let url = format!("https://data.weather.gov.hk/weatherAPI/opendata/lunardate.php?date={}", date.format("%Y-%m-%d"));
+	let lunar = fetch_parsed::<LunarDate>(&url).await?;
+
+	CACHE.write().await.insert(date, lunar.clone());
+	Some(lunar)
+}
+
+/// The apparent ecliptic longitude of the sun in degrees, by the low accuracy
+/// formulae of Meeus, which are good to about 0.01 degree.
+fn sun_longitude(time: DateTime<Utc>) -> f64 {
+	#[allow(clippy::cast_precision_loss)]
+	let jd = time.timestamp() as f64 / 86400.0 + 2_440_587.5;
+	let t = (jd - 2_451_545.0) / 36525.0;
+
+	let l0 = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+	let m = (357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t).to_radians();
+	let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+		+ (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+		+ 0.000_289 * (3.0 * m).sin();
+	let omega = (125.04 - 1934.136 * t).to_radians();
+
+	(l0 + c - 0.005_69 - 0.004_78 * omega.sin()).rem_euclid(360.0)
+}
+
+/// The solar term on the date in Hong Kong, as the multiple of 15 degrees of
+/// the longitude of the sun, i.e. 0 for the Vernal Equinox.
+pub fn solar_term(date: NaiveDate) -> Option<usize> {
+	let start = date.and_time(NaiveTime::MIN).and_utc() - TimeDelta::hours(8);
+	let end = start + TimeDelta::days(1);
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let term = |time| (sun_longitude(time) / 15.0) as usize;
+
+	let next = term(end);
+	(term(start) != next).then_some(next)
+}
+
+/// The date of the next solar term on or after the date, and the term.
+pub fn next_solar_term(date: NaiveDate) -> Option<(NaiveDate, usize)> {
+	date.iter_days().take(20).find_map(|date| solar_term(date).map(|term| (date, term)))
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test() {
+		use chrono::NaiveDate;
+
+		use std::str::FromStr as _;
+
+		use super::{LunarDate, next_solar_term, solar_term};
+
+		let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+		assert_eq!(solar_term(date(2024, 12, 21)), Some(18));
+		assert_eq!(solar_term(date(2025, 3, 20)), Some(0));
+		assert_eq!(solar_term(date(2026, 4, 5)), Some(1));
+		assert_eq!(solar_term(date(2026, 4, 4)), None);
+		assert_eq!(next_solar_term(date(2026, 3, 25)), Some((date(2026, 4, 5), 1)));
+
+		let lunar = LunarDate::from_str(r#"{"LunarYear":"丙午年，馬","LunarDate":"九月初八"}"#).unwrap();
+		assert_eq!((lunar.year.as_str(), lunar.date.as_str()), ("丙午年，馬", "九月初八"));
+
+		let lunar =
+			LunarDate::from_str(r#"{ "LunarYear" : "\u4e19\u5348\u5e74\uff0c\u99ac", "LunarDate" : "\u521d\u4e00" }"#)
+				.unwrap();
+		assert_eq!((lunar.year.as_str(), lunar.date.as_str()), ("丙午年，馬", "初一"));
+
+		assert!(LunarDate::from_str(r#"{"LunarYear":"丙午年，馬"}"#).is_err());
+	}
+}
@@ -0,0 +1,113 @@
+// Copyright (c) 2026 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, sync::LazyLock};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use hko::opendata::{SeaStation, hlt::Response};
+use tokio::sync::RwLock;
+
+use super::fetch_open_data;
+
+/// Tide stations as (station, Chinese name, English name).
+pub const STATIONS: [(SeaStation, &str, &str); 14] = [
+	(SeaStation::CCH, "長洲", "Cheung Chau"),
+	(SeaStation::CLK, "赤鱲角", "Chek Lap Kok"),
+	(SeaStation::CMW, "芝麻灣", "Chi Ma Wan"),
+	(SeaStation::KCT, "葵涌", "Kwai Chung"),
+	(SeaStation::KLW, "高流灣", "Ko Lau Wan"),
+	(SeaStation::LOP, "樂安排", "Lok On Pai"),
+	(SeaStation::MWC, "馬灣", "Ma Wan"),
+	(SeaStation::QUB, "鰂魚涌", "Quarry Bay"),
+	(SeaStation::SPW, "石壁", "Shek Pik"),
+	(SeaStation::TAO, "大澳", "Tai O"),
+	(SeaStation::TBT, "尖鼻咀", "Tsim Bei Tsui"),
+	(SeaStation::TMW, "大廟灣", "Tai Miu Wan"),
+	(SeaStation::TPK, "大埔滘", "Tai Po Kau"),
+	(SeaStation::WAG, "橫瀾島", "Waglan Island"),
+];
+
+pub const DEFAULT_STATION: SeaStation = SeaStation::QUB;
+
+#[derive(Clone)]
+pub struct Tide {
+	pub time: NaiveDateTime,
+	/// Metres above Chart Datum
+	pub height: f32,
+	pub high: bool,
+}
+
+/// Tides keyed by station code and year
+type Predictions = HashMap<(String, i32), Vec<Tide>>;
+
+/// The predictions of a year never change, so each station and year is
+/// fetched only once.
+static CACHE: LazyLock<RwLock<Predictions>> = LazyLock::new(RwLock::default);
+
+/// Finds the station by its code, or its Chinese or English name.
+pub fn find_station(query: &str) -> Option<SeaStation> {
+	let query = query.trim();
+	let lowercase = query.to_lowercase();
+
+	STATIONS
+		.iter()
+		.find(|(station, zh, en)| {
+			station.to_string().eq_ignore_ascii_case(query)
+				|| zh.contains(query)
+				|| en.to_lowercase().contains(&lowercase)
+		})
+		.map(|&(station, ..)| station)
+}
+
+pub fn station_name(station: SeaStation) -> (&'static str, &'static str) {
+	STATIONS.iter().find(|(s, ..)| *s == station).map(|&(_, zh, en)| (zh, en)).unwrap_or_default()
+}
+
+async fn fetch(station: SeaStation, year: i32) -> Option<Vec<Tide>> {
+	let response = fetch_open_data::<Response>(&format!("dataType=HLT&station={station}&year={year}")).await?;
+
+	let mut tides = response
+		.0
+		.into_iter()
+		.filter_map(|unit| {
+			let date = NaiveDate::from_ymd_opt(year, unit.month, unit.day)?;
+			let time = NaiveTime::from_hms_opt(unit.hour, unit.minute, 0)?;
+			Some((date.and_time(time), unit.height))
+		})
+		.collect::<Vec<_>>();
+	tides.sort_by_key(|&(time, _)| time);
+
+	// High and low tides alternate, so each is told apart by its neighbour.
+	let tides = tides
+		.iter()
+		.enumerate()
+		.map(|(i, &(time, height))| {
+			let neighbour = if i == 0 { tides.get(1) } else { tides.get(i - 1) };
+			Tide { time, height, high: neighbour.is_some_and(|&(_, h)| height > h) }
+		})
+		.collect();
+
+	Some(tides)
+}
+
+/// Gets the tides of the station from and to the dates, inclusive.
+pub async fn get(station: SeaStation, from: NaiveDate, to: NaiveDate) -> Option<Vec<Tide>> {
+	let mut tides = Vec::new();
+
+	for year in from.year()..=to.year() {
+		let key = (station.to_string(), year);
+
+		let cached = CACHE.read().await.get(&key).cloned();
+		let yearly = if let Some(yearly) = cached {
+			yearly
+		} else {
+			let yearly = fetch(station, year).await?;
+			CACHE.write().await.insert(key, yearly.clone());
+			yearly
+		};
+
+		tides.extend(yearly.into_iter().filter(|t| (from..=to).contains(&t.time.date())));
+	}
+
+	Some(tides)
+}
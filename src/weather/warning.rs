@@ -1,4 +1,4 @@
-// Copyright (c) 2022 - 2025 GreenYun Organization
+// Copyright (c) 2022 - 2026 GreenYun Organization
 // SPDX-License-Identifier: MIT
 
 use std::{fmt::Write, sync::OnceLock};
@@ -13,6 +13,8 @@ use super::{WeatherData, WeatherDataUpdater};
 
 #[derive(Clone, Default)]
 pub struct Piece {
+	pub code: String,
+	pub subtype: String,
 	pub name: BilingualString,
 	pub contents: Vec<BilingualString>,
 	pub update_time: DateTime<FixedOffset>,
@@ -20,6 +22,9 @@ pub struct Piece {
 
 impl Piece {
 	pub fn new(zh: InfoDetail, en: InfoDetail) -> Self {
+		let code = format!("{:?}", zh.code);
+		let subtype = zh.subtype.as_ref().map(|code| format!("{code:?}")).unwrap_or_default();
+
 		let mut chinese_name = format!("{:o}", zh.code);
 		let mut english_name = format!("{:e}", en.code);
 
@@ -29,6 +34,8 @@ impl Piece {
 		}
 
 		Self {
+			code,
+			subtype,
 			name: BilingualString::new(chinese_name, english_name),
 			contents: zh
 				.contents
@@ -62,6 +69,45 @@ impl Piece {
 	}
 }
 
+impl Piece {
+	pub fn severity(&self) -> u8 {
+		severity(&self.code, &self.subtype)
+	}
+}
+
+/// Ranks the warning by its HKO warning code and subtype, the higher the more
+/// severe.
+pub fn severity(code: &str, subtype: &str) -> u8 {
+	match (code, subtype) {
+		("WTCSGNL", "TC10") => 10,
+		("WTCSGNL", "TC9") | ("WTMW", _) => 9,
+		("WTCSGNL", "TC8NE" | "TC8SE" | "TC8SW" | "TC8NW") | ("WRAIN", "WRAINB") => 8,
+		("WRAIN", "WRAINR") => 7,
+		("WTCSGNL", "TC3") | ("WL", _) => 6,
+		("WRAIN", _) | ("WFNTSA", _) => 5,
+		("WTS", _) | ("WFIRE", "WFIRER") => 4,
+		("WTCSGNL", _) | ("WMSGNL", _) => 3,
+		("WHOT", _) | ("WCOLD", _) | ("WFROST", _) => 2,
+		_ => 1,
+	}
+}
+
+/// HKO warning codes that chats can choose to receive, with their names.
+pub const CODES: [(&str, &str, &str); 12] = [
+	("WTCSGNL", "熱帶氣旋警告信號", "Tropical Cyclone Warning Signal"),
+	("WTCPRE8", "預警八號熱帶氣旋警告信號的特別報告", "Pre-No. 8 Special Announcement"),
+	("WRAIN", "暴雨警告信號", "Rainstorm Warning Signal"),
+	("WFNTSA", "新界北部水浸特別報告", "Special Announcement on Flooding in Northern New Territories"),
+	("WL", "山泥傾瀉警告", "Landslip Warning"),
+	("WTS", "雷暴警告", "Thunderstorm Warning"),
+	("WMSGNL", "強烈季候風信號", "Strong Monsoon Signal"),
+	("WFIRE", "火災危險警告", "Fire Danger Warning"),
+	("WHOT", "酷熱天氣警告", "Very Hot Weather Warning"),
+	("WCOLD", "寒冷天氣警告", "Cold Weather Warning"),
+	("WFROST", "霜凍警告", "Frost Warning"),
+	("WTMW", "海嘯警告", "Tsunami Warning"),
+];
+
 #[derive(Clone, Default)]
 pub struct Warning {
 	pub pieces: Vec<Piece>,